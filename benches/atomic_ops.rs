@@ -0,0 +1,77 @@
+//! Benchmarks comparing the allocating atomic operations with their buffer-reusing `_into` variants.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use timekeep_rs::AtomicInterval;
+
+/// Builds `n` atoms of width 4 starting every 3 units, so that consecutive atoms overlap.
+fn atoms(n: i64) -> Vec<AtomicInterval<i64>> {
+    (0..n).map(|i| AtomicInterval::closed(3 * i, 3 * i + 4)).collect()
+}
+
+fn bench_difference(c: &mut Criterion) {
+    let atoms = atoms(1_000);
+    let subtrahend = AtomicInterval::open(1, 2);
+    let mut group = c.benchmark_group("atomic_difference");
+
+    group.bench_function("difference", |b| {
+        b.iter(|| {
+            let mut total = 0;
+            for atom in &atoms {
+                for other in atoms.iter().take(8) {
+                    total += atom.difference(black_box(other)).len();
+                }
+                total += atom.difference(black_box(&subtrahend)).len();
+            }
+            total
+        })
+    });
+    group.bench_function("difference_into", |b| {
+        let mut out = Vec::new();
+        b.iter(|| {
+            let mut total = 0;
+            for atom in &atoms {
+                for other in atoms.iter().take(8) {
+                    out.clear();
+                    atom.difference_into(black_box(other), &mut out);
+                    total += out.len();
+                }
+                out.clear();
+                atom.difference_into(black_box(&subtrahend), &mut out);
+                total += out.len();
+            }
+            total
+        })
+    });
+    group.finish();
+}
+
+fn bench_intersection(c: &mut Criterion) {
+    let atoms = atoms(1_000);
+    let mut group = c.benchmark_group("atomic_intersection");
+
+    group.bench_function("intersection", |b| {
+        b.iter(|| {
+            let mut total = 0;
+            for pair in atoms.windows(2) {
+                total += pair[0].intersection(black_box(&pair[1])).len();
+            }
+            total
+        })
+    });
+    group.bench_function("intersection_into", |b| {
+        let mut out = Vec::new();
+        b.iter(|| {
+            out.clear();
+            for pair in atoms.windows(2) {
+                pair[0].intersection_into(black_box(&pair[1]), &mut out);
+            }
+            out.len()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_difference, bench_intersection);
+criterion_main!(benches);
@@ -0,0 +1,78 @@
+//! Benchmarks of the set operations on synthetic interval sets.
+//!
+//! Two scenarios are measured for each size:
+//! - `dense`: every atom of one set overlaps atoms of the other set.
+//! - `sparse`: the atoms of both sets interleave without ever overlapping.
+//!
+//! The atoms are generated from a fixed seed, so that runs are reproducible.
+//! `intersection` and `difference` compare every pair of atoms, so the largest sizes take a while.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use timekeep_rs::{AtomicInterval, IntervalSet};
+
+const SIZES: [i64; 3] = [1_000, 10_000, 100_000];
+
+/// Builds the pair of sets of a scenario, given the number of atoms of each set.
+type Scenario = fn(i64) -> (IntervalSet<i64>, IntervalSet<i64>);
+
+/// A minimal linear congruential generator, to avoid depending on a random number crate.
+struct Lcg(u64);
+
+impl Lcg {
+    /// Returns a pseudo-random value in `0..bound`.
+    fn next(&mut self, bound: i64) -> i64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((self.0 >> 33) % bound as u64) as i64
+    }
+}
+
+/// Builds a set of `n` atoms, one every 20 units, starting `offset` units into each slot.
+/// Each atom is between `min_width` and `min_width + 3` units wide.
+fn set(n: i64, offset: i64, min_width: i64, rng: &mut Lcg) -> IntervalSet<i64> {
+    let atoms = (0..n)
+        .map(|i| {
+            let left = 20 * i + offset;
+            AtomicInterval::closed_open(left, left + min_width + rng.next(4))
+        })
+        .collect::<Vec<_>>();
+    IntervalSet::from(atoms)
+}
+
+/// Builds two sets of `n` atoms whose atoms overlap each other.
+fn dense(n: i64) -> (IntervalSet<i64>, IntervalSet<i64>) {
+    let mut rng = Lcg(42);
+    (set(n, 0, 12, &mut rng), set(n, 8, 12, &mut rng))
+}
+
+/// Builds two sets of `n` atoms that interleave without overlapping.
+fn sparse(n: i64) -> (IntervalSet<i64>, IntervalSet<i64>) {
+    let mut rng = Lcg(42);
+    (set(n, 0, 4, &mut rng), set(n, 10, 4, &mut rng))
+}
+
+fn bench_set_ops(c: &mut Criterion) {
+    let scenarios: [(&str, Scenario); 2] = [("dense", dense), ("sparse", sparse)];
+
+    for (scenario, build) in scenarios {
+        let mut group = c.benchmark_group(scenario);
+        group.sample_size(10);
+        for n in SIZES {
+            let (a, b) = build(n);
+            group.bench_with_input(BenchmarkId::new("union", n), &(&a, &b), |bench, (a, b)| {
+                bench.iter(|| black_box(a).union(black_box(b)))
+            });
+            group.bench_with_input(BenchmarkId::new("intersection", n), &(&a, &b), |bench, (a, b)| {
+                bench.iter(|| black_box(a).intersection(black_box(b)))
+            });
+            group.bench_with_input(BenchmarkId::new("difference", n), &(&a, &b), |bench, (a, b)| {
+                bench.iter(|| black_box(a).difference(black_box(b)))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_set_ops);
+criterion_main!(benches);
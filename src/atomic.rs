@@ -10,7 +10,15 @@
 //! assert_eq!(*interval.right(), Bound::Included(5));
 //! ```
 //!
-use crate::Bound;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+use core::ops::{Add, Div, Mul, Rem, Sub};
+
+use crate::{AllenRelation, Bound, CheckedOps, Discrete, IntervalError, IntervalSet, PointRelation, Side};
 
 /// A struct representing an atomic interval.
 /// An atomic interval is a closed or open interval that contains a single value or a range of values.
@@ -34,26 +42,192 @@ pub struct AtomicInterval<T> {
     right: Bound<T>,
 }
 
+/// The outcome of merging two atomic intervals with [`AtomicInterval::merge`].
+/// 
+/// # Examples
+/// ```
+/// use timekeep_rs::{AtomicInterval, MergeResult};
+///
+/// let merged = AtomicInterval::closed(1, 5).merge(&AtomicInterval::closed(4, 7));
+/// assert_eq!(merged, MergeResult::Merged(AtomicInterval::closed(1, 7)));
+/// ```
+/// 
+#[derive(PartialEq, Debug, Clone)]
+pub enum MergeResult<T> {
+    /// The intervals overlap or are adjacent, and were merged into a single interval.
+    Merged(AtomicInterval<T>),
+    /// The intervals are apart, and are returned ordered by their left bound.
+    Disjoint(AtomicInterval<T>, AtomicInterval<T>),
+}
+
 
-/// Implementation of the `ToString` trait for `AtomicInterval`.
-impl<T: ToString> ToString for AtomicInterval<T> {
-    /// This allows `AtomicInterval` to be converted to a string.
+/// Implementation of the `Display` trait for `AtomicInterval`.
+impl<T: fmt::Display> fmt::Display for AtomicInterval<T> {
+    /// This allows `AtomicInterval` to be formatted and converted to a string.
+    /// A requested precision is applied to both bound values. The interval is written straight into
+    /// the formatter, unless a width is requested, in which case the whole rendered interval is padded
+    /// and aligned.
     /// 
     /// # Returns
     /// A string representation of the `AtomicInterval`
-    fn to_string(&self) -> String {
-        match (&self.left, &self.right) {
-            (Bound::Included(l), Bound::Included(r)) => format!("[{}, {}]", l.to_string(), r.to_string()),
-            (Bound::Included(l), Bound::Excluded(r)) => format!("[{}, {})", l.to_string(), r.to_string()),
-            (Bound::Excluded(l), Bound::Included(r)) => format!("({}, {}]", l.to_string(), r.to_string()),
-            (Bound::Excluded(l), Bound::Excluded(r)) => format!("({}, {})", l.to_string(), r.to_string()),
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed_open(1, 5);
+    /// assert_eq!(format!("{}", interval), "[1, 5)");
+    /// assert_eq!(format!("{:>10}", interval), "    [1, 5)");
+    /// assert_eq!(format!("{:<14.2}|", AtomicInterval::closed(1.5, 2.25)), "[1.50, 2.25]  |");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        match f.width() {
+            Some(width) => {
+                // The rendered length is needed to pad, so only then render into a `String` first.
+                let mut rendered = String::new();
+                self.render(&mut rendered, precision)?;
+                pad(f, &rendered, width)
+            }
+            None => self.render(f, precision),
+        }
+    }
+}
+
+impl<T: fmt::Display> AtomicInterval<T> {
+    /// Writes the textual representation of the interval into `w`, formatting both bound values
+    /// with `precision` if one is given.
+    pub(crate) fn render<W: fmt::Write>(&self, w: &mut W, precision: Option<usize>) -> fmt::Result {
+        let (open, left) = match &self.left {
+            Bound::Included(l) => ('[', l),
+            Bound::Excluded(l) => ('(', l),
+        };
+        let (close, right) = match &self.right {
+            Bound::Included(r) => (']', r),
+            Bound::Excluded(r) => (')', r),
+        };
+        match precision {
+            Some(p) => write!(w, "{}{:.*}, {:.*}{}", open, p, left, p, right, close),
+            None => write!(w, "{}{}, {}{}", open, left, right, close),
         }
     }
 }
 
+/// Writes `rendered` into `f`, padded with the formatter's fill character up to `width` and aligned
+/// as requested, left by default.
+/// Unlike `Formatter::pad`, this never truncates `rendered`, as the precision was already applied
+/// to the values inside it.
+pub(crate) fn pad(f: &mut fmt::Formatter<'_>, rendered: &str, width: usize) -> fmt::Result {
+    let padding = width.saturating_sub(rendered.chars().count());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
+    }
+    f.write_str(rendered)?;
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
+
+/// Implementation of the `FromStr` trait for `AtomicInterval`.
+impl<T: FromStr + PartialOrd + Clone> FromStr for AtomicInterval<T> {
+    type Err = IntervalError;
+
+    /// Parses an interval from its textual representation, as produced by `Display`.
+    /// The text is an opening bracket, `[` for an included bound or `(` for an excluded one, the two
+    /// bound values separated by a comma, and a closing bracket, `]` or `)`. Whitespace around the
+    /// values is ignored. The values must not contain a comma themselves.
+    /// 
+    /// # Returns
+    /// The parsed `AtomicInterval`, `Err(IntervalError::InvalidFormat)` if the text is malformed or a value
+    /// cannot be parsed, or `Err(IntervalError::InvalidBounds)` if the bounds do not enclose any point
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, IntervalError};
+    /// 
+    /// assert_eq!("[1, 5)".parse(), Ok(AtomicInterval::closed_open(1, 5)));
+    /// assert_eq!("(1.5,2]".parse(), Ok(AtomicInterval::open_closed(1.5, 2.0)));
+    /// assert_eq!("[1; 5]".parse::<AtomicInterval<i32>>(), Err(IntervalError::InvalidFormat));
+    /// assert_eq!("[5, 1]".parse::<AtomicInterval<i32>>(), Err(IntervalError::InvalidBounds));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let inner = s.get(1..s.len().saturating_sub(1)).ok_or(IntervalError::InvalidFormat)?;
+        let (left, right) = inner.split_once(',').ok_or(IntervalError::InvalidFormat)?;
+        let left: T = left.trim().parse().map_err(|_| IntervalError::InvalidFormat)?;
+        let right: T = right.trim().parse().map_err(|_| IntervalError::InvalidFormat)?;
+
+        let left = match s.chars().next() {
+            Some('[') => Bound::Included(left),
+            Some('(') => Bound::Excluded(left),
+            _ => return Err(IntervalError::InvalidFormat),
+        };
+        let right = match s.chars().last() {
+            Some(']') => Bound::Included(right),
+            Some(')') => Bound::Excluded(right),
+            _ => return Err(IntervalError::InvalidFormat),
+        };
+        AtomicInterval::new(left, right)
+    }
+}
+
+impl<T: PartialOrd + Clone> From<(T, T)> for AtomicInterval<T> {
+    /// Creates a closed interval [a,b] from a `(left, right)` tuple, same as [`AtomicInterval::closed`].
+    ///
+    /// # Panics
+    /// Like [`AtomicInterval::closed`], this panics unless `left < right`.
+    /// Use [`AtomicInterval::closed_or_point`] to handle equal or reversed values without panicking.
+    ///
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval = AtomicInterval::from((1, 5));
+    /// assert_eq!(interval, AtomicInterval::closed(1, 5));
+    /// ```
+    fn from((left, right): (T, T)) -> Self {
+        AtomicInterval::closed(left, right)
+    }
+}
 
 /// A collection of constructors for creating different types of atomic intervals.
 impl<T: Clone + PartialOrd> AtomicInterval<T> {
+    /// Creates an interval from arbitrary bounds.
+    /// The bounds must satisfy `left < right`, or `left == right` with both bounds included for a point.
+    ///
+    /// # Arguments
+    /// * `left` - The left bound of the interval
+    /// * `right` - The right bound of the interval
+    ///
+    /// # Returns
+    /// A new `AtomicInterval` with the given bounds, or `Err(IntervalError::InvalidBounds)` if they do not enclose any point
+    ///
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, Bound, IntervalError};
+    ///
+    /// let interval = AtomicInterval::new(Bound::Included(1), Bound::Excluded(5));
+    /// assert_eq!(interval, Ok(AtomicInterval::closed_open(1, 5)));
+    ///
+    /// let invalid = AtomicInterval::new(Bound::Included(5), Bound::Excluded(5));
+    /// assert_eq!(invalid, Err(IntervalError::InvalidBounds));
+    /// ```
+    pub fn new(left: Bound<T>, right: Bound<T>) -> Result<Self, IntervalError> {
+        let interval = AtomicInterval { left, right };
+        if interval.is_valid() {
+            Ok(interval)
+        } else {
+            Err(IntervalError::InvalidBounds)
+        }
+    }
+
     /// Creates an open interval (a,b) that excludes both endpoints.
     ///
     /// # Arguments
@@ -62,11 +236,12 @@ impl<T: Clone + PartialOrd> AtomicInterval<T> {
     ///
     /// # Returns
     /// A new `AtomicInterval` with excluded endpoints
+    ///
+    /// # Panics
+    /// Panics unless `left < right`. Use [`AtomicInterval::try_open`] to get an error instead.
     pub fn open(left: T, right: T) -> Self {
-        if left >= right {
-            panic!("The following condition must be valid: `left < right`");
-        }
-        AtomicInterval { left: Bound::Excluded(left), right: Bound::Excluded(right) }
+        AtomicInterval::try_open(left, right)
+            .unwrap_or_else(|_| panic!("The following condition must be valid: `left < right`"))
     }
 
     /// Creates a closed interval [a,b] that includes both endpoints.
@@ -77,11 +252,12 @@ impl<T: Clone + PartialOrd> AtomicInterval<T> {
     ///
     /// # Returns
     /// A new `AtomicInterval` with included endpoints
+    ///
+    /// # Panics
+    /// Panics unless `left < right`. Use [`AtomicInterval::try_closed`] to get an error instead.
     pub fn closed(left: T, right: T) -> Self {
-        if left >= right {
-            panic!("The following condition must be valid: `left < right`");
-        }
-        AtomicInterval { left: Bound::Included(left), right: Bound::Included(right) }
+        AtomicInterval::try_closed(left, right)
+            .unwrap_or_else(|_| panic!("The following condition must be valid: `left < right`"))
     }
 
     /// Creates a left-open, right-closed interval (a,b] that excludes the left endpoint and includes the right endpoint.
@@ -92,11 +268,12 @@ impl<T: Clone + PartialOrd> AtomicInterval<T> {
     ///
     /// # Returns
     /// A new `AtomicInterval` with excluded left endpoint and included right endpoint
+    ///
+    /// # Panics
+    /// Panics unless `left < right`. Use [`AtomicInterval::try_open_closed`] to get an error instead.
     pub fn open_closed(left: T, right: T) -> Self {
-        if left >= right {
-            panic!("The following condition must be valid: `left < right`");
-        }
-        AtomicInterval { left: Bound::Excluded(left), right: Bound::Included(right) }
+        AtomicInterval::try_open_closed(left, right)
+            .unwrap_or_else(|_| panic!("The following condition must be valid: `left < right`"))
     }
 
     /// Creates a left-closed, right-open interval [a,b) that includes the left endpoint and excludes the right endpoint.
@@ -107,11 +284,12 @@ impl<T: Clone + PartialOrd> AtomicInterval<T> {
     ///
     /// # Returns
     /// A new `AtomicInterval` with included left endpoint and excluded right endpoint
+    ///
+    /// # Panics
+    /// Panics unless `left < right`. Use [`AtomicInterval::try_closed_open`] to get an error instead.
     pub fn closed_open(left: T, right: T) -> Self {
-        if left >= right {
-            panic!("The following condition must be valid: `left < right`");
-        }
-        AtomicInterval { left: Bound::Included(left), right: Bound::Excluded(right) }
+        AtomicInterval::try_closed_open(left, right)
+            .unwrap_or_else(|_| panic!("The following condition must be valid: `left < right`"))
     }
 
     /// Creates a point interval [a,a] containing a single value.
@@ -124,514 +302,3289 @@ impl<T: Clone + PartialOrd> AtomicInterval<T> {
     pub fn point(value: T) -> Self {
         AtomicInterval { left: Bound::Included(value.clone()), right: Bound::Included(value) }
     }
-}
 
-
-impl<T> AtomicInterval<T> {
-    /// Return a reference to the left bound.
-    /// 
+    /// Creates a closed interval [a,b], or a point interval [a,a] if both endpoints are equal.
+    ///
+    /// # Arguments
+    /// * `left` - The left endpoint of the interval
+    /// * `right` - The right endpoint of the interval
+    ///
     /// # Returns
-    /// A reference of `Bound` associated to the left bound.
-    pub fn left(&self) -> &Bound<T> {
-        &self.left
+    /// A new `AtomicInterval` with included endpoints, or `Err(IntervalError::InvalidBounds)` if `left > right`
+    ///
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, IntervalError};
+    ///
+    /// assert_eq!(AtomicInterval::closed_or_point(1, 5), Ok(AtomicInterval::closed(1, 5)));
+    /// assert_eq!(AtomicInterval::closed_or_point(3, 3), Ok(AtomicInterval::point(3)));
+    /// assert_eq!(AtomicInterval::closed_or_point(5, 1), Err(IntervalError::InvalidBounds));
+    /// ```
+    pub fn closed_or_point(left: T, right: T) -> Result<Self, IntervalError> {
+        AtomicInterval::new(Bound::Included(left), Bound::Included(right))
     }
 
-    /// Return a reference to the right bound.
-    /// 
+    /// Creates an open interval (a,b), failing instead of panicking on invalid endpoints.
+    ///
+    /// # Arguments
+    /// * `left` - The left endpoint of the interval
+    /// * `right` - The right endpoint of the interval
+    ///
     /// # Returns
-    /// A reference of `Bound` associated to the right bound.
-    pub fn right(&self) -> &Bound<T> {
-        &self.right
+    /// A new `AtomicInterval`, or `Err(IntervalError::InvalidBounds)` unless `left < right`
+    ///
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, IntervalError};
+    ///
+    /// assert_eq!(AtomicInterval::try_open(1, 5), Ok(AtomicInterval::open(1, 5)));
+    /// assert_eq!(AtomicInterval::try_open(5, 5), Err(IntervalError::InvalidBounds));
+    /// ```
+    pub fn try_open(left: T, right: T) -> Result<Self, IntervalError> {
+        if left >= right {
+            return Err(IntervalError::InvalidBounds);
+        }
+        Ok(AtomicInterval { left: Bound::Excluded(left), right: Bound::Excluded(right) })
     }
-}
 
-/// A collection of methods for performing set operations on atomic intervals.
-impl <T: PartialOrd> AtomicInterval<T> {
-    /// Checks if the interval is a superset of another interval.
-    /// An interval is a superset of another if it contains all the elements of the other interval.
-    /// 
+    /// Creates a closed interval [a,b], failing instead of panicking on invalid endpoints.
+    ///
     /// # Arguments
-    /// * `other` - The other interval to check if it is a subset of the current interval
-    /// 
+    /// * `left` - The left endpoint of the interval
+    /// * `right` - The right endpoint of the interval
+    ///
     /// # Returns
-    /// `true` if the current interval is a superset of the other interval, `false` otherwise
-    /// 
+    /// A new `AtomicInterval`, or `Err(IntervalError::InvalidBounds)` unless `left < right`
+    ///
     /// # Examples
     /// ```
-    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::{AtomicInterval, IntervalError};
     ///
-    /// let interval1 = AtomicInterval::closed(1, 5);
-    /// let interval2 = AtomicInterval::closed(2, 4);
-    /// assert!(interval1.is_superset(&interval2));
+    /// assert_eq!(AtomicInterval::try_closed(1, 5), Ok(AtomicInterval::closed(1, 5)));
+    /// assert_eq!(AtomicInterval::try_closed(5, 5), Err(IntervalError::InvalidBounds));
     /// ```
-    /// 
-    pub fn is_superset (&self, other: &AtomicInterval<T>) -> bool {
-        match (&self.left, &self.right, &other.left, &other.right) {
-            (Bound::Included(l1), Bound::Excluded(r1), _, Bound::Included(r2)) => l1 <= other.left.value() && r1 > r2,
-            (Bound::Excluded(l1), Bound::Included(r1), Bound::Included(l2), _) => l1 < l2 && r1 >= other.right.value(),
-            (Bound::Excluded(l1), Bound::Excluded(r1), Bound::Included(l2), Bound::Included(r2)) => l1 < l2 && r1 > r2,
-            (_, _, _, _) => self.left.value() <= other.left.value() && self.right.value() >= other.right.value(),
+    pub fn try_closed(left: T, right: T) -> Result<Self, IntervalError> {
+        if left >= right {
+            return Err(IntervalError::InvalidBounds);
         }
+        Ok(AtomicInterval { left: Bound::Included(left), right: Bound::Included(right) })
     }
 
-    /// Checks if the interval is a subset of another interval.
-    /// An interval is a subset of another if it is contained within the other interval.
-    /// 
+    /// Creates a left-open, right-closed interval (a,b], failing instead of panicking on invalid endpoints.
+    ///
     /// # Arguments
-    /// * `other` - The other interval to check if it is a superset of the current interval
+    /// * `left` - The left endpoint of the interval
+    /// * `right` - The right endpoint of the interval
     ///
     /// # Returns
-    /// `true` if the current interval is a subset of the other interval, `false` otherwise
-    /// 
+    /// A new `AtomicInterval`, or `Err(IntervalError::InvalidBounds)` unless `left < right`
+    ///
     /// # Examples
     /// ```
-    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::{AtomicInterval, IntervalError};
     ///
-    /// let interval1 = AtomicInterval::closed(2, 4);
-    /// let interval2 = AtomicInterval::closed(1, 5);
-    /// assert!(interval1.is_subset(&interval2));
+    /// assert_eq!(AtomicInterval::try_open_closed(1, 5), Ok(AtomicInterval::open_closed(1, 5)));
+    /// assert_eq!(AtomicInterval::try_open_closed(5, 5), Err(IntervalError::InvalidBounds));
     /// ```
-    /// 
-    pub fn is_subset (&self, other: &AtomicInterval<T>) -> bool {
-        other.is_superset(self)
+    pub fn try_open_closed(left: T, right: T) -> Result<Self, IntervalError> {
+        if left >= right {
+            return Err(IntervalError::InvalidBounds);
+        }
+        Ok(AtomicInterval { left: Bound::Excluded(left), right: Bound::Included(right) })
     }
 
-    /// Checks if the interval is overlapping with another interval.
-    /// Two intervals are overlapping if they share at least one common point.
-    /// 
+    /// Creates a left-closed, right-open interval [a,b), failing instead of panicking on invalid endpoints.
+    ///
     /// # Arguments
-    /// * `other` - The other interval to check if it is overlapping with the current interval
-    /// 
+    /// * `left` - The left endpoint of the interval
+    /// * `right` - The right endpoint of the interval
+    ///
     /// # Returns
-    /// `true` if the current interval is overlapping with the other interval, `false` otherwise
-    /// 
+    /// A new `AtomicInterval`, or `Err(IntervalError::InvalidBounds)` unless `left < right`
+    ///
     /// # Examples
     /// ```
-    /// use timekeep_rs::AtomicInterval;
-    /// 
-    /// let interval1 = AtomicInterval::closed(1, 5);
-    /// let interval2 = AtomicInterval::closed(4, 6);
-    /// assert!(interval1.is_overlapping(&interval2));
+    /// use timekeep_rs::{AtomicInterval, IntervalError};
+    ///
+    /// assert_eq!(AtomicInterval::try_closed_open(1, 5), Ok(AtomicInterval::closed_open(1, 5)));
+    /// assert_eq!(AtomicInterval::try_closed_open(5, 5), Err(IntervalError::InvalidBounds));
     /// ```
-    /// 
-    pub fn is_overlapping (&self, other: &AtomicInterval<T>) -> bool {
-        // Check if the intervals are overlapping on left side of other
-        let cond1_overlapping = match (&self.left, &self.right, &other.left) {
-            (Bound::Included(l1), Bound::Included(r1), _) => other.left.value() >= l1 && other.left.value() <= r1,
-            (Bound::Included(l1), Bound::Excluded(r1), Bound::Included(l2)) => l2 >= l1 && l2 < r1,
-            (Bound::Included(l1), Bound::Excluded(r1), Bound::Excluded(l2)) => l2 >= l1 && l2 <= r1,
-            (Bound::Excluded(l1), Bound::Included(r1), Bound::Included(l2)) => l2 > l1 && l2 <= r1,
-            (Bound::Excluded(l1), Bound::Included(r1), Bound::Excluded(l2)) => l2 >= l1 && l2 <= r1,
-            (Bound::Excluded(l1), Bound::Excluded(r1), Bound::Included(l2)) => l2 > l1 && l2 < r1,
-            (Bound::Excluded(l1), Bound::Excluded(r1), Bound::Excluded(l2)) => l2 >= l1 && l2 <= r1,
-        };
-        // Check if the intervals are overlapping on right side of other
-        let cond2_overlapping = match (&self.left, &self.right, &other.right) {
-            (Bound::Included(l1), Bound::Included(r1), _) => other.right.value() >= l1 && other.right.value() <= r1,
-            (Bound::Included(l1), Bound::Excluded(r1), Bound::Included(r2)) => r2 > l1 && r2 <= r1,
-            (Bound::Included(l1), Bound::Excluded(r1), Bound::Excluded(r2)) => r2 >= l1 && r2 <= r1,
-            (Bound::Excluded(l1), Bound::Included(r1), Bound::Included(r2)) => r2 >= l1 && r2 < r1,
-            (Bound::Excluded(l1), Bound::Included(r1), Bound::Excluded(r2)) => r2 >= l1 && r2 <= r1,
-            (Bound::Excluded(l1), Bound::Excluded(r1), Bound::Included(r2)) => r2 > l1 && r2 < r1,
-            (Bound::Excluded(l1), Bound::Excluded(r1), Bound::Excluded(r2)) => r2 >= l1 && r2 <= r1,
-        };
-        // They overlap if either condition is true
-        return cond1_overlapping || cond2_overlapping;
+    pub fn try_closed_open(left: T, right: T) -> Result<Self, IntervalError> {
+        if left >= right {
+            return Err(IntervalError::InvalidBounds);
+        }
+        Ok(AtomicInterval { left: Bound::Included(left), right: Bound::Excluded(right) })
     }
 
-    /// Checks if the interval is adjacent to another interval.
-    /// Two intervals are adjacent if they share a common boundary, but do not overlap.
-    /// 
+    /// Returns a copy of the interval with a different left bound.
+    ///
     /// # Arguments
-    /// * `other` - The other interval to check if it is adjacent to the current interval
-    /// 
+    /// * `left` - The new left bound
+    ///
     /// # Returns
-    /// `true` if the current interval is adjacent to the other interval, `false` otherwise
-    /// 
+    /// `Some(AtomicInterval)` with the new left bound, or `None` if it would make the interval invalid
+    ///
     /// # Examples
     /// ```
-    /// use timekeep_rs::AtomicInterval;
-    /// 
-    /// let interval1 = AtomicInterval::closed(1, 5);
-    /// let interval2 = AtomicInterval::open_closed(5, 10);
-    /// assert!(interval1.is_adjacent(&interval2));
+    /// use timekeep_rs::{AtomicInterval, Bound};
+    ///
+    /// let interval = AtomicInterval::closed(1, 10);
+    /// assert_eq!(interval.with_left(Bound::Included(3)), Some(AtomicInterval::closed(3, 10)));
+    /// assert_eq!(interval.with_left(Bound::Included(20)), None);
     /// ```
-    pub fn is_adjacent(&self, other: &AtomicInterval<T>) -> bool {
-        // Check if the intervals are adjacent on left side of other
-        let cond1_adjacent = match (&self.left, &other.right) {
-            (Bound::Excluded(_), Bound::Excluded(_)) => false,
-            (Bound::Included(_), Bound::Included(_)) => false,
-            (_, _) => self.left.value() == other.right.value(),
-        };
-        // Check if the intervals are adjacent on right side of other
-        let cond2_adjacent = match (&self.right, &other.left) {
-            (Bound::Excluded(_), Bound::Excluded(_)) => false,
-            (Bound::Included(_), Bound::Included(_)) => false,
-            (_, _) => self.right.value() == other.left.value(),
-        };
-
-        return cond1_adjacent || cond2_adjacent;
+    pub fn with_left(&self, left: Bound<T>) -> Option<Self> {
+        AtomicInterval::new(left, self.right.clone()).ok()
     }
 
-    /// Checks if the interval is disjoint from another interval.
-    /// Two intervals are disjoint if they do not share any common points.
-    /// 
+    /// Returns a copy of the interval with a different right bound.
+    ///
     /// # Arguments
-    /// * `other` - The other interval to check if it is disjoint from the current interval
-    /// 
+    /// * `right` - The new right bound
+    ///
     /// # Returns
-    /// `true` if the current interval is disjoint from the other interval, `false` otherwise
-    /// 
+    /// `Some(AtomicInterval)` with the new right bound, or `None` if it would make the interval invalid
+    ///
     /// # Examples
     /// ```
-    /// use timekeep_rs::AtomicInterval;
-    /// 
-    /// let interval1 = AtomicInterval::closed(1, 5);
-    /// let interval2 = AtomicInterval::closed(6, 10);
-    /// assert!(interval1.is_disjoint(&interval2));
+    /// use timekeep_rs::{AtomicInterval, Bound};
+    ///
+    /// let interval = AtomicInterval::closed(1, 10);
+    /// assert_eq!(interval.with_right(Bound::Excluded(5)), Some(AtomicInterval::closed_open(1, 5)));
+    /// assert_eq!(interval.with_right(Bound::Included(0)), None);
     /// ```
-    /// 
-    pub fn is_disjoint(&self, other: &AtomicInterval<T>) -> bool {
-        // Check if the intervals are disjoint on one side
-        let cond1_disjoint = match (&self.left, &other.right) {
-            (Bound::Included(l1), Bound::Included(r2)) => l1 > r2,
-            (_, _) => return self.right.value() <= other.left.value(),
-        };
-
-        // Check if the intervals are disjoint on the other side
-        let cond2_disjoint = match (&self.right, &other.left) {
-            (Bound::Included(r1), Bound::Included(l2)) => r1 < l2,
-            (_, _) => return self.left.value() >= other.right.value(),
-        };
-
-        return cond1_disjoint || cond2_disjoint;
+    pub fn with_right(&self, right: Bound<T>) -> Option<Self> {
+        AtomicInterval::new(self.left.clone(), right).ok()
     }
-}
 
-impl <T: PartialOrd + Clone> AtomicInterval<T> {
-    /// Computes the union of two overlapping or adjacent intervals.
-    /// The union of two intervals is the smallest interval that contains both intervals.
-    /// 
+    /// Trims off the part of the interval lying before a value.
+    ///
     /// # Arguments
-    /// * `a` - The first interval to union
-    /// * `b` - The second interval to union
-    /// 
+    /// * `value` - The value from which to keep the interval
+    ///
     /// # Returns
-    /// A `Vec` containing the union of the two intervals if they are overlapping or adjacent, an empty `Vec` otherwise
-    /// 
+    /// `Some(AtomicInterval)` with the part of the interval at or after `value`, starting with
+    /// `Included(value)` unless `value` lies left of the interval, which is then kept whole,
+    /// or `None` if `value` lies past the right bound
+    ///
     /// # Examples
     /// ```
     /// use timekeep_rs::AtomicInterval;
-    /// 
-    /// let interval1 = AtomicInterval::closed(1, 5);
-    /// let interval2 = AtomicInterval::closed(4, 7);
-    /// let merged = AtomicInterval::union(&interval1, &interval2);
-    /// 
-    /// assert_eq!(merged.len(), 1);
-    /// assert_eq!(merged.first().unwrap(), &AtomicInterval::closed(1, 7));
+    ///
+    /// let interval = AtomicInterval::closed(1, 10);
+    /// assert_eq!(interval.trim_left(&4), Some(AtomicInterval::closed(4, 10)));
+    /// assert_eq!(interval.trim_left(&0), Some(interval.clone()));
+    /// assert_eq!(interval.trim_left(&11), None);
     /// ```
-    /// 
-    pub fn union(a: &AtomicInterval<T>, b: &AtomicInterval<T>) -> Vec<AtomicInterval<T>> {
-        if a.is_overlapping(b) || a.is_adjacent(b) {
-            let left = if a.left.value() <= b.left.value() {
-                a.left.clone()
-            } else {
-                b.left.clone()
-            };
-            let right = if a.right.value() >= b.right.value() {
-                a.right.clone()
-            } else {
-                b.right.clone()
-            };
-            vec![AtomicInterval { left, right }]
-        } else {
-            vec![]
+    pub fn trim_left(&self, value: &T) -> Option<Self> {
+        if value <= self.left.value() {
+            return Some(self.clone());
         }
+        self.with_left(Bound::Included(value.clone()))
     }
 
-    /// Computes the intersection of two overlapping intervals.
-    /// The intersection of two intervals is the largest interval that is contained within both intervals.
+    /// Trims off the part of the interval lying after a value.
+    ///
+    /// # Arguments
+    /// * `value` - The value up to which to keep the interval
+    ///
+    /// # Returns
+    /// `Some(AtomicInterval)` with the part of the interval at or before `value`, ending with
+    /// `Included(value)` unless `value` lies right of the interval, which is then kept whole,
+    /// or `None` if `value` lies before the left bound
+    ///
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval = AtomicInterval::closed(1, 10);
+    /// assert_eq!(interval.trim_right(&4), Some(AtomicInterval::closed(1, 4)));
+    /// assert_eq!(interval.trim_right(&12), Some(interval.clone()));
+    /// assert_eq!(interval.trim_right(&0), None);
+    /// ```
+    pub fn trim_right(&self, value: &T) -> Option<Self> {
+        if value >= self.right.value() {
+            return Some(self.clone());
+        }
+        self.with_right(Bound::Included(value.clone()))
+    }
+
+    /// Returns a copy of the interval with the same bound values and the given inclusivity.
+    ///
+    /// # Arguments
+    /// * `left_inc` - Whether the new left bound is included
+    /// * `right_inc` - Whether the new right bound is included
+    ///
+    /// # Returns
+    /// `Some(AtomicInterval)` with the requested inclusivity, or `None` if it would make the interval
+    /// empty, as when excluding a bound of a point interval
+    ///
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval = AtomicInterval::closed(1, 5);
+    /// assert_eq!(interval.with_inclusivity(false, false), Some(AtomicInterval::open(1, 5)));
+    /// assert_eq!(AtomicInterval::point(3).with_inclusivity(true, false), None);
+    /// ```
+    pub fn with_inclusivity(&self, left_inc: bool, right_inc: bool) -> Option<Self> {
+        let bound = |value: &T, inc: bool| {
+            if inc { Bound::Included(value.clone()) } else { Bound::Excluded(value.clone()) }
+        };
+        AtomicInterval::new(bound(self.left.value(), left_inc), bound(self.right.value(), right_inc)).ok()
+    }
+
+    /// Returns the closure of the interval, i.e. the interval with both bounds included.
+    ///
+    /// # Returns
+    /// A new `AtomicInterval` with the same bound values, both included
+    ///
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval = AtomicInterval::open_closed(1, 5);
+    /// assert_eq!(interval.closure(), AtomicInterval::closed(1, 5));
+    /// ```
+    pub fn closure(&self) -> Self {
+        AtomicInterval {
+            left: Bound::Included(self.left.value().clone()),
+            right: Bound::Included(self.right.value().clone()),
+        }
+    }
+
+    /// Returns the interior of the interval, i.e. the interval with both bounds excluded.
+    ///
+    /// # Returns
+    /// `Some(AtomicInterval)` with the same bound values, both excluded, or `None` for a point interval, whose interior is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval = AtomicInterval::closed(1, 5);
+    /// assert_eq!(interval.interior(), Some(AtomicInterval::open(1, 5)));
+    /// assert_eq!(AtomicInterval::point(3).interior(), None);
+    /// ```
+    pub fn interior(&self) -> Option<Self> {
+        AtomicInterval::new(
+            Bound::Excluded(self.left.value().clone()),
+            Bound::Excluded(self.right.value().clone()),
+        ).ok()
+    }
+}
+
+
+impl<T> AtomicInterval<T> {
+    /// Return a reference to the left bound.
+    /// 
+    /// # Returns
+    /// A reference of `Bound` associated to the left bound.
+    pub fn left(&self) -> &Bound<T> {
+        &self.left
+    }
+
+    /// Return a reference to the right bound.
+    /// 
+    /// # Returns
+    /// A reference of `Bound` associated to the right bound.
+    pub fn right(&self) -> &Bound<T> {
+        &self.right
+    }
+
+    /// Return a reference to the bound on the given side.
     /// 
     /// # Arguments
-    /// * `other` - The other interval to intersect with the current interval
+    /// * `side` - The side of the bound to return
     /// 
     /// # Returns
-    /// A `Vec` containing the intersection of the two intervals if they are overlapping, an empty `Vec` otherwise
+    /// A reference of `Bound` associated to the left bound for `Side::Left`, or to the right bound for `Side::Right`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, Bound, Side};
+    /// 
+    /// let interval = AtomicInterval::open_closed(1, 5);
+    /// for side in [Side::Left, Side::Right] {
+    ///     assert!(matches!(interval.bound(side), Bound::Excluded(_)) == (side == Side::Left));
+    /// }
+    /// ```
+    pub fn bound(&self, side: Side) -> &Bound<T> {
+        match side {
+            Side::Left => &self.left,
+            Side::Right => &self.right,
+        }
+    }
+
+    /// Return references to both bounds.
+    /// 
+    /// # Returns
+    /// A pair of references to the left and right `Bound`s.
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, Bound};
+    ///
+    /// let interval = AtomicInterval::closed_open(1, 5);
+    /// assert_eq!(interval.bounds(), (&Bound::Included(1), &Bound::Excluded(5)));
+    /// ```
+    pub fn bounds(&self) -> (&Bound<T>, &Bound<T>) {
+        (&self.left, &self.right)
+    }
+
+    /// Consumes the interval and returns both bounds, without cloning them.
+    /// 
+    /// # Returns
+    /// A pair of the left and right `Bound`s.
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, Bound};
+    ///
+    /// let interval = AtomicInterval::closed_open(1, 5);
+    /// assert_eq!(interval.into_bounds(), (Bound::Included(1), Bound::Excluded(5)));
+    /// ```
+    pub fn into_bounds(self) -> (Bound<T>, Bound<T>) {
+        (self.left, self.right)
+    }
+
+    /// Checks if both sides of the interval are bounded by a value.
+    /// Every `Bound` variant currently carries a value, so this always holds.
+    /// 
+    /// # Returns
+    /// `true` if neither side of the interval is unbounded, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval = AtomicInterval::open(1, 5);
+    /// assert!(interval.is_bounded());
+    /// ```
+    pub fn is_bounded(&self) -> bool {
+        match (&self.left, &self.right) {
+            (Bound::Included(_) | Bound::Excluded(_), Bound::Included(_) | Bound::Excluded(_)) => true,
+        }
+    }
+
+    /// Checks if either side of the interval is unbounded.
+    /// 
+    /// # Returns
+    /// `true` if at least one side of the interval is unbounded, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval = AtomicInterval::closed(1, 5);
+    /// assert!(!interval.is_unbounded());
+    /// ```
+    pub fn is_unbounded(&self) -> bool {
+        !self.is_bounded()
+    }
+}
+
+/// A collection of methods for performing set operations on atomic intervals.
+impl <T: PartialOrd> AtomicInterval<T> {
+    /// Checks if the interval contains a value.
+    /// 
+    /// # Arguments
+    /// * `value` - The value to check
+    /// 
+    /// # Returns
+    /// `true` if the value lies within the interval bounds, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval = AtomicInterval::closed_open(1, 5);
+    /// assert!(interval.contains(&1));
+    /// assert!(!interval.contains(&5));
+    /// ```
+    /// 
+    pub fn contains(&self, value: &T) -> bool {
+        let cond_left = match &self.left {
+            Bound::Included(l) => value >= l,
+            Bound::Excluded(l) => value > l,
+        };
+        let cond_right = match &self.right {
+            Bound::Included(r) => value <= r,
+            Bound::Excluded(r) => value < r,
+        };
+        cond_left && cond_right
+    }
+
+    /// Checks if the interval contains every value of a slice.
+    /// 
+    /// # Arguments
+    /// * `values` - The values to check
+    /// 
+    /// # Returns
+    /// `true` if all the values lie within the interval bounds, including when `values` is empty, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval = AtomicInterval::closed(1, 5);
+    /// assert!(interval.contains_all(&[1, 3, 5]));
+    /// assert!(!interval.contains_all(&[1, 6]));
+    /// ```
+    /// 
+    pub fn contains_all(&self, values: &[T]) -> bool {
+        values.iter().all(|value| self.contains(value))
+    }
+
+    /// Checks if the interval contains at least one value of a slice.
+    /// 
+    /// # Arguments
+    /// * `values` - The values to check
+    /// 
+    /// # Returns
+    /// `true` if any of the values lies within the interval bounds, `false` otherwise, including when `values` is empty
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval = AtomicInterval::closed(1, 5);
+    /// assert!(interval.contains_any(&[0, 3]));
+    /// assert!(!interval.contains_any(&[0, 6]));
+    /// ```
+    /// 
+    pub fn contains_any(&self, values: &[T]) -> bool {
+        values.iter().any(|value| self.contains(value))
+    }
+
+    /// Checks if the interval contains a value.
+    /// This is an alias of [`AtomicInterval::contains`].
+    /// 
+    /// # Arguments
+    /// * `value` - The value to check
+    /// 
+    /// # Returns
+    /// `true` if the value lies within the interval bounds, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval = AtomicInterval::open(1, 5);
+    /// assert!(interval.contains_point(&3));
+    /// assert!(!interval.contains_point(&5));
+    /// ```
+    /// 
+    pub fn contains_point(&self, value: &T) -> bool {
+        self.contains(value)
+    }
+
+    /// Checks if the interval denotes the same set of points as another interval.
+    /// Unlike the derived `PartialEq`, the bound values are compared through `PartialOrd`.
+    /// As constructors reject empty intervals, every interval has a single representation
+    /// and this agrees with `==` for types whose `PartialEq` and `PartialOrd` are consistent.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to compare with the current interval
+    /// 
+    /// # Returns
+    /// `true` if both intervals contain exactly the same points, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, Bound};
+    ///
+    /// let interval = AtomicInterval::new(Bound::Included(3), Bound::Included(3)).unwrap();
+    /// assert!(interval.equivalent(&AtomicInterval::point(3)));
+    /// ```
+    /// 
+    pub fn equivalent(&self, other: &AtomicInterval<T>) -> bool {
+        if !self.is_valid() || !other.is_valid() {
+            return !self.is_valid() && !other.is_valid();
+        }
+        self.left.cmp_as_left(&other.left) == Ordering::Equal
+            && self.right.cmp_as_right(&other.right) == Ordering::Equal
+    }
+
+    /// Checks if the interval is a superset of another interval.
+    /// An interval is a superset of another if it contains all the elements of the other interval.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to check if it is a subset of the current interval
+    /// 
+    /// # Returns
+    /// `true` if the current interval is a superset of the other interval, `false` otherwise
     /// 
     /// # Examples
     /// ```
     /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval1 = AtomicInterval::closed(1, 5);
+    /// let interval2 = AtomicInterval::closed(2, 4);
+    /// assert!(interval1.is_superset(&interval2));
+    /// ```
+    /// 
+    pub fn is_superset (&self, other: &AtomicInterval<T>) -> bool {
+        // Check if the current interval starts at or before the other one, and ends at or after it
+        self.left.cmp_as_left(&other.left) != Ordering::Greater
+            && self.right.cmp_as_right(&other.right) != Ordering::Less
+    }
+
+    /// Checks if the interval is a proper superset of another interval.
+    /// An interval is a proper superset of another if it is a superset of it, and holds at least one more element.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to check if it is a proper subset of the current interval
+    /// 
+    /// # Returns
+    /// `true` if the current interval is a proper superset of the other interval, `false` otherwise
     /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
     /// let interval1 = AtomicInterval::closed(1, 5);
-    /// let interval2 = AtomicInterval::closed(3, 7);
-    /// let intersection = interval1.intersection(&interval2);
+    /// assert!(interval1.is_proper_superset(&AtomicInterval::closed_open(1, 5)));
+    /// assert!(!interval1.is_proper_superset(&AtomicInterval::closed(1, 5)));
+    /// ```
     /// 
-    /// assert_eq!(intersection.len(), 1);
-    /// assert_eq!(intersection.first().unwrap(), &AtomicInterval::closed(3, 5));
+    pub fn is_proper_superset(&self, other: &AtomicInterval<T>) -> bool {
+        self.is_superset(other) && !self.equivalent(other)
+    }
+
+    /// Checks if the interval contains another interval, optionally requiring a strict containment.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to check if it is contained in the current interval
+    /// * `strict` - Whether the current interval must also hold at least one element not in the other interval
+    /// 
+    /// # Returns
+    /// The result of [`AtomicInterval::is_proper_superset`] if `strict` is `true`,
+    /// the result of [`AtomicInterval::is_superset`] otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval = AtomicInterval::closed(1, 5);
+    /// assert!(interval.contains_interval(&AtomicInterval::closed(1, 5), false));
+    /// assert!(!interval.contains_interval(&AtomicInterval::closed(1, 5), true));
+    /// assert!(interval.contains_interval(&AtomicInterval::open(1, 5), true));
     /// ```
     /// 
-    pub fn intersection(&self, other: &Self) -> Vec<Self> {
-        // If they're disjoint, there's no intersection.
-        if self.is_disjoint(other) {
-            return vec![];
+    pub fn contains_interval(&self, other: &AtomicInterval<T>, strict: bool) -> bool {
+        if strict {
+            self.is_proper_superset(other)
+        } else {
+            self.is_superset(other)
         }
+    }
+
+    /// Checks if the interval is a subset of another interval.
+    /// An interval is a subset of another if it is contained within the other interval.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to check if it is a superset of the current interval
+    ///
+    /// # Returns
+    /// `true` if the current interval is a subset of the other interval, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let interval1 = AtomicInterval::closed(2, 4);
+    /// let interval2 = AtomicInterval::closed(1, 5);
+    /// assert!(interval1.is_subset(&interval2));
+    /// ```
+    /// 
+    pub fn is_subset (&self, other: &AtomicInterval<T>) -> bool {
+        other.is_superset(self)
+    }
+
+    /// Checks if the interval is overlapping with another interval.
+    /// Two intervals are overlapping if they share at least one common point.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to check if it is overlapping with the current interval
+    /// 
+    /// # Returns
+    /// `true` if the current interval is overlapping with the other interval, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval1 = AtomicInterval::closed(1, 5);
+    /// let interval2 = AtomicInterval::closed(4, 6);
+    /// assert!(interval1.is_overlapping(&interval2));
+    /// ```
+    /// 
+    pub fn is_overlapping (&self, other: &AtomicInterval<T>) -> bool {
+        // They overlap if the latest start still comes before the earliest end
+        let left = match self.left.cmp_as_left(&other.left) {
+            Ordering::Less => &other.left,
+            _ => &self.left,
+        };
+        let right = match self.right.cmp_as_right(&other.right) {
+            Ordering::Greater => &other.right,
+            _ => &self.right,
+        };
+        match (left, right) {
+            (Bound::Included(l), Bound::Included(r)) => l <= r,
+            (_, _) => left.value() < right.value(),
+        }
+    }
+
+    /// Checks if the closures of the interval and another interval touch, sharing a boundary value.
+    /// Unlike [`AtomicInterval::is_adjacent`], inclusivity is ignored, so `[1, 5]` touches both `(5, 8)` and `[5, 8]`.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to check if it touches the current interval
+    /// 
+    /// # Returns
+    /// `true` if the right bound of one interval has the same value as the left bound of the other, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed(1, 5);
+    /// assert!(interval.touches(&AtomicInterval::open(5, 8)));
+    /// assert!(!interval.touches(&AtomicInterval::open(6, 8)));
+    /// ```
+    /// 
+    pub fn touches(&self, other: &AtomicInterval<T>) -> bool {
+        self.right.value() == other.left.value() || other.right.value() == self.left.value()
+    }
+
+    /// Checks if the interval is adjacent to another interval.
+    /// Two intervals are adjacent if they share a common boundary, but do not overlap.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to check if it is adjacent to the current interval
+    /// 
+    /// # Returns
+    /// `true` if the current interval is adjacent to the other interval, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval1 = AtomicInterval::closed(1, 5);
+    /// let interval2 = AtomicInterval::open_closed(5, 10);
+    /// assert!(interval1.is_adjacent(&interval2));
+    /// ```
+    pub fn is_adjacent(&self, other: &AtomicInterval<T>) -> bool {
+        // Check if the intervals are adjacent on left side of other
+        let cond1_adjacent = match (&self.left, &other.right) {
+            (Bound::Excluded(_), Bound::Excluded(_)) => false,
+            (Bound::Included(_), Bound::Included(_)) => false,
+            (_, _) => self.left.value() == other.right.value(),
+        };
+        // Check if the intervals are adjacent on right side of other
+        let cond2_adjacent = match (&self.right, &other.left) {
+            (Bound::Excluded(_), Bound::Excluded(_)) => false,
+            (Bound::Included(_), Bound::Included(_)) => false,
+            (_, _) => self.right.value() == other.left.value(),
+        };
+
+        cond1_adjacent || cond2_adjacent
+    }
+
+    /// Checks if the interval is disjoint from another interval.
+    /// Two intervals are disjoint if they do not share any common points.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to check if it is disjoint from the current interval
+    /// 
+    /// # Returns
+    /// `true` if the current interval is disjoint from the other interval, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval1 = AtomicInterval::closed(1, 5);
+    /// let interval2 = AtomicInterval::closed(6, 10);
+    /// assert!(interval1.is_disjoint(&interval2));
+    /// ```
+    /// 
+    pub fn is_disjoint(&self, other: &AtomicInterval<T>) -> bool {
+        !self.is_overlapping(other)
+    }
+
+    /// Checks if the interval lies entirely before another interval.
+    /// Intervals sharing an included endpoint overlap, so neither lies before the other.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to compare with the current interval
+    /// 
+    /// # Returns
+    /// `true` if every point of the current interval precedes every point of the other interval, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval1 = AtomicInterval::closed(1, 3);
+    /// let interval2 = AtomicInterval::closed(5, 8);
+    /// assert!(interval1.is_before(&interval2));
+    /// assert!(!interval2.is_before(&interval1));
+    /// ```
+    /// 
+    pub fn is_before(&self, other: &AtomicInterval<T>) -> bool {
+        match (&self.right, &other.left) {
+            (Bound::Included(r1), Bound::Included(l2)) => r1 < l2,
+            (_, _) => self.right.value() <= other.left.value(),
+        }
+    }
+
+    /// Checks if the interval lies entirely after another interval.
+    /// Intervals sharing an included endpoint overlap, so neither lies after the other.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to compare with the current interval
+    /// 
+    /// # Returns
+    /// `true` if every point of the current interval follows every point of the other interval, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval1 = AtomicInterval::closed(5, 8);
+    /// let interval2 = AtomicInterval::closed(1, 3);
+    /// assert!(interval1.is_after(&interval2));
+    /// ```
+    /// 
+    pub fn is_after(&self, other: &AtomicInterval<T>) -> bool {
+        other.is_before(self)
+    }
+
+    /// Checks if the interval starts before another interval, comparing their left bounds.
+    /// At equal values, an included left bound starts before an excluded one, as `[1` holds 1 and `(1` does not.
+    /// This is the ordering by which interval sets sort their intervals.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to compare with
+    /// 
+    /// # Returns
+    /// `true` if the left bound of the interval comes strictly before the left bound of the other interval, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed(1, 5);
+    /// assert!(interval.left_before(&AtomicInterval::open(1, 5)));
+    /// assert!(!interval.left_before(&AtomicInterval::closed(1, 3)));
+    /// ```
+    /// 
+    pub fn left_before(&self, other: &AtomicInterval<T>) -> bool {
+        self.left.cmp_as_left(&other.left) == Ordering::Less
+    }
+
+    /// Checks if the interval ends before another interval, comparing their right bounds.
+    /// At equal values, an excluded right bound ends before an included one, as `5)` does not hold 5 and `5]` does.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to compare with
+    /// 
+    /// # Returns
+    /// `true` if the right bound of the interval comes strictly before the right bound of the other interval, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed_open(1, 5);
+    /// assert!(interval.right_before(&AtomicInterval::closed(1, 5)));
+    /// assert!(!interval.right_before(&AtomicInterval::open(3, 5)));
+    /// ```
+    /// 
+    pub fn right_before(&self, other: &AtomicInterval<T>) -> bool {
+        self.right.cmp_as_right(&other.right) == Ordering::Less
+    }
+
+    /// Computes the relation between the interval and another interval, following Allen's interval algebra.
+    /// The relation accounts for the inclusivity of the bounds: intervals sharing a single included
+    /// point overlap, while intervals touching without sharing a point meet.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to relate the current interval to
+    /// 
+    /// # Returns
+    /// The `AllenRelation` holding between the current interval and the other interval
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AllenRelation, AtomicInterval};
+    /// 
+    /// let interval1 = AtomicInterval::closed(2, 4);
+    /// let interval2 = AtomicInterval::closed(1, 5);
+    /// assert_eq!(interval1.relate(&interval2), AllenRelation::During);
+    /// assert_eq!(interval2.relate(&interval1), AllenRelation::Contains);
+    /// ```
+    /// 
+    pub fn relate(&self, other: &AtomicInterval<T>) -> AllenRelation {
+        let left_ordering = self.left.cmp_as_left(&other.left);
+        let right_ordering = self.right.cmp_as_right(&other.right);
+        match (left_ordering, right_ordering) {
+            (Ordering::Equal, Ordering::Equal) => AllenRelation::Equals,
+            (Ordering::Equal, Ordering::Less) => AllenRelation::Starts,
+            (Ordering::Equal, Ordering::Greater) => AllenRelation::StartedBy,
+            (Ordering::Greater, Ordering::Equal) => AllenRelation::Finishes,
+            (Ordering::Less, Ordering::Equal) => AllenRelation::FinishedBy,
+            (Ordering::Greater, Ordering::Less) => AllenRelation::During,
+            (Ordering::Less, Ordering::Greater) => AllenRelation::Contains,
+            (Ordering::Less, Ordering::Less) if self.is_overlapping(other) => AllenRelation::Overlaps,
+            (Ordering::Less, Ordering::Less) if self.is_adjacent(other) => AllenRelation::Meets,
+            (Ordering::Less, Ordering::Less) => AllenRelation::Before,
+            (Ordering::Greater, Ordering::Greater) if self.is_overlapping(other) => AllenRelation::OverlappedBy,
+            (Ordering::Greater, Ordering::Greater) if self.is_adjacent(other) => AllenRelation::MetBy,
+            (Ordering::Greater, Ordering::Greater) => AllenRelation::After,
+        }
+    }
+
+    /// Computes where a value lies relative to the interval.
+    /// A value equal to a bound is reported on that bound even if the bound is excluded.
+    /// For a point interval, the value of the point is reported on the left bound.
+    /// 
+    /// # Arguments
+    /// * `value` - The value to locate
+    /// 
+    /// # Returns
+    /// `Some(PointRelation)` holding between the value and the interval, or `None` if the value
+    /// cannot be compared with the bounds, such as `NaN`
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, PointRelation};
+    /// 
+    /// let interval = AtomicInterval::open(1, 5);
+    /// assert_eq!(interval.relate_point(&1), Some(PointRelation::OnLeftBound));
+    /// assert_eq!(interval.relate_point(&3), Some(PointRelation::Inside));
+    /// assert_eq!(interval.relate_point(&7), Some(PointRelation::After));
+    /// assert_eq!(AtomicInterval::open(1.0, 5.0).relate_point(&f64::NAN), None);
+    /// ```
+    /// 
+    pub fn relate_point(&self, value: &T) -> Option<PointRelation> {
+        let (left, right) = (self.left.value(), self.right.value());
+        if value == left {
+            Some(PointRelation::OnLeftBound)
+        } else if value == right {
+            Some(PointRelation::OnRightBound)
+        } else if value < left {
+            Some(PointRelation::Before)
+        } else if value > right {
+            Some(PointRelation::After)
+        } else if value > left && value < right {
+            Some(PointRelation::Inside)
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the bounds of the interval enclose at least one point, as every interval should.
+    /// Bounds with equal values only enclose a point when both are included.
+    /// 
+    /// The constructors never produce an invalid interval, so this is meant for asserting
+    /// the invariant in tests and on values coming from outside the library.
+    /// 
+    /// # Returns
+    /// `true` if the left bound lies before the right bound, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// assert!(AtomicInterval::closed(1, 5).is_valid());
+    /// assert!(AtomicInterval::point(1).is_valid());
+    /// ```
+    /// 
+    pub fn is_valid(&self) -> bool {
+        match (&self.left, &self.right) {
+            (Bound::Included(l), Bound::Included(r)) => l <= r,
+            (_, _) => self.left.value() < self.right.value(),
+        }
+    }
+}
+
+impl <T: PartialOrd + Clone> AtomicInterval<T> {
+    /// Computes the union of two overlapping or adjacent intervals.
+    /// The union of two intervals is the smallest interval that contains both intervals.
+    /// 
+    /// # Arguments
+    /// * `a` - The first interval to union
+    /// * `b` - The second interval to union
+    /// 
+    /// # Returns
+    /// A `Vec` containing the union of the two intervals if they are overlapping or adjacent, an empty `Vec` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval1 = AtomicInterval::closed(1, 5);
+    /// let interval2 = AtomicInterval::closed(4, 7);
+    /// let merged = AtomicInterval::union(&interval1, &interval2);
+    /// 
+    /// assert_eq!(merged.len(), 1);
+    /// assert_eq!(merged.first().unwrap(), &AtomicInterval::closed(1, 7));
+    /// ```
+    /// 
+    pub fn union(a: &AtomicInterval<T>, b: &AtomicInterval<T>) -> Vec<AtomicInterval<T>> {
+        match a.merge(b) {
+            MergeResult::Merged(merged) => vec![merged],
+            MergeResult::Disjoint(_, _) => vec![],
+        }
+    }
+
+    /// Merges the interval with another interval, making explicit whether they could be merged.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to merge with the current interval
+    /// 
+    /// # Returns
+    /// `MergeResult::Merged` with the union of the two intervals if they are overlapping or adjacent,
+    /// `MergeResult::Disjoint` with both intervals ordered by their left bound otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, MergeResult};
+    /// 
+    /// let interval1 = AtomicInterval::closed(6, 8);
+    /// let interval2 = AtomicInterval::closed(1, 5);
+    /// assert_eq!(interval1.merge(&interval2), MergeResult::Disjoint(interval2.clone(), interval1.clone()));
+    /// ```
+    /// 
+    pub fn merge(&self, other: &Self) -> MergeResult<T> {
+        if !self.is_overlapping(other) && !self.is_adjacent(other) {
+            return match self.left.cmp_as_left(&other.left) {
+                Ordering::Greater => MergeResult::Disjoint(other.clone(), self.clone()),
+                _ => MergeResult::Disjoint(self.clone(), other.clone()),
+            };
+        }
+
+        // Keep the left bound that starts first and the right bound that ends last.
+        let left = match self.left.cmp_as_left(&other.left) {
+            Ordering::Greater => other.left.clone(),
+            _ => self.left.clone(),
+        };
+        let right = match self.right.cmp_as_right(&other.right) {
+            Ordering::Less => other.right.clone(),
+            _ => self.right.clone(),
+        };
+        MergeResult::Merged(AtomicInterval { left, right })
+    }
+
+    /// Computes the union of two intervals, keeping both when they cannot be merged.
+    /// Unlike [`AtomicInterval::union`], the result is never empty, which suits folding over intervals.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to merge with
+    /// 
+    /// # Returns
+    /// A `Vec` with the union of the two intervals if they are overlapping or adjacent,
+    /// or with both intervals ordered by their left bound otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed(1, 3);
+    /// assert_eq!(interval.union_keep(&AtomicInterval::closed(2, 5)), vec![AtomicInterval::closed(1, 5)]);
+    /// assert_eq!(
+    ///     interval.union_keep(&AtomicInterval::closed(-2, 0)),
+    ///     vec![AtomicInterval::closed(-2, 0), AtomicInterval::closed(1, 3)],
+    /// );
+    /// ```
+    /// 
+    pub fn union_keep(&self, other: &Self) -> Vec<Self> {
+        match self.merge(other) {
+            MergeResult::Merged(merged) => vec![merged],
+            MergeResult::Disjoint(first, second) => vec![first, second],
+        }
+    }
+
+    /// Computes the intersection of two overlapping intervals.
+    /// The intersection of two intervals is the largest interval that is contained within both intervals.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to intersect with the current interval
+    /// 
+    /// # Returns
+    /// A `Vec` containing the intersection of the two intervals if they are overlapping, an empty `Vec` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval1 = AtomicInterval::closed(1, 5);
+    /// let interval2 = AtomicInterval::closed(3, 7);
+    /// let intersection = interval1.intersection(&interval2);
+    /// 
+    /// assert_eq!(intersection.len(), 1);
+    /// assert_eq!(intersection.first().unwrap(), &AtomicInterval::closed(3, 5));
+    /// ```
+    /// 
+    pub fn intersection(&self, other: &Self) -> Vec<Self> {
+        let mut result = Vec::new();
+        self.intersection_into(other, &mut result);
+        result
+    }
+
+    /// Computes the intersection of two intervals like [`AtomicInterval::intersection`],
+    /// appending it to a caller-provided buffer instead of allocating one.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to intersect with the current interval
+    /// * `out` - The buffer the intersection is pushed to, if the intervals are overlapping
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let mut out = Vec::new();
+    /// AtomicInterval::closed(1, 5).intersection_into(&AtomicInterval::closed(3, 7), &mut out);
+    /// AtomicInterval::closed(1, 5).intersection_into(&AtomicInterval::closed(6, 7), &mut out);
+    /// assert_eq!(out, vec![AtomicInterval::closed(3, 5)]);
+    /// ```
+    /// 
+    pub fn intersection_into(&self, other: &Self, out: &mut Vec<Self>) {
+        out.extend(self.intersect(other));
+    }
+
+    /// Computes the intersection of two overlapping intervals, as an `Option`.
+    /// This is the ergonomic form of [`AtomicInterval::intersection`], as an intersection holds at most one interval.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to intersect with the current interval
+    /// 
+    /// # Returns
+    /// `Some(AtomicInterval)` with the intersection of the two intervals if they are overlapping, `None` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval1 = AtomicInterval::closed(1, 5);
+    /// assert_eq!(interval1.intersect(&AtomicInterval::closed(3, 7)), Some(AtomicInterval::closed(3, 5)));
+    /// assert_eq!(interval1.intersect(&AtomicInterval::closed(6, 7)), None);
+    /// ```
+    /// 
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        // If they're disjoint, there's no intersection.
+        if self.is_disjoint(other) {
+            return None;
+        }
+
+        // Determine the left boundary of the intersection, the one that starts last.
+        let left = match self.left.cmp_as_left(&other.left) {
+            Ordering::Less => other.left.clone(),
+            _ => self.left.clone(),
+        };
+
+        // Determine the right boundary of the intersection, the one that ends first.
+        let right = match self.right.cmp_as_right(&other.right) {
+            Ordering::Greater => other.right.clone(),
+            _ => self.right.clone(),
+        };
+
+        // If they meet at a single point, ensure it's included on both sides.
+        if left.value() == right.value() {
+            return match (left, right) {
+                (Bound::Included(val), Bound::Included(_)) => {
+                    Some(AtomicInterval { left: Bound::Included(val.clone()), right: Bound::Included(val) })
+                }
+                _ => None,
+            };
+        }
+
+        // Otherwise, we have a valid overlapping range.
+        Some(AtomicInterval { left, right })
+    }
+
+    /// Computes the difference between two intervals.
+    /// The difference between two intervals is the set of intervals that are in the first interval but not in the second interval.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to compute the difference with the current interval
+    /// 
+    /// # Returns
+    /// A `Vec` of `AtomicInterval` representing the difference between the two intervals
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval1 = AtomicInterval::closed(1, 5);
+    /// let interval2 = AtomicInterval::closed(3, 7);
+    /// let difference = interval1.difference(&interval2);
+    /// assert_eq!(difference.len(), 1);
+    /// assert_eq!(difference[0], AtomicInterval::closed_open(1, 3));
+    /// ```
+    /// 
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let mut result = Vec::new();
+        self.difference_into(other, &mut result);
+        result
+    }
+
+    /// Computes the difference between two intervals like [`AtomicInterval::difference`],
+    /// appending the resulting intervals to a caller-provided buffer instead of allocating one.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to subtract from the current interval
+    /// * `out` - The buffer the remaining intervals are pushed to, in ascending order
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let mut out = Vec::new();
+    /// AtomicInterval::closed(1, 10).difference_into(&AtomicInterval::closed(4, 6), &mut out);
+    /// assert_eq!(out, vec![AtomicInterval::closed_open(1, 4), AtomicInterval::open_closed(6, 10)]);
+    /// ```
+    /// 
+    pub fn difference_into(&self, other: &Self, out: &mut Vec<Self>) {
+        // If disjoint, difference is just self.
+        if self.is_disjoint(other) {
+            out.push(self.clone());
+            return;
+        } else if self.is_subset(other) {
+            return;
+        }
+
+        // They overlap, so there is an intersection to cut out.
+        let intersection = self.intersect(other).expect("No intersection found!");
+
+        // Left remainder: from self.left up to intersection.left, with its inclusivity flipped.
+        let left_interval = AtomicInterval {
+            left: self.left.clone(),
+            right: match &intersection.left {
+                Bound::Included(val) => Bound::Excluded(val.clone()),
+                Bound::Excluded(val) => Bound::Included(val.clone()),
+            },
+        };
+        // Only add if it holds at least one point.
+        if left_interval.is_valid() {
+            out.push(left_interval);
+        }
+
+        // Right remainder: from intersection.right up to self.right, with its inclusivity flipped.
+        let right_interval = AtomicInterval {
+            left: match &intersection.right {
+                Bound::Included(val) => Bound::Excluded(val.clone()),
+                Bound::Excluded(val) => Bound::Included(val.clone()),
+            },
+            right: self.right.clone(),
+        };
+        // Only add if it holds at least one point.
+        if right_interval.is_valid() {
+            out.push(right_interval);
+        }
+    }
+
+    /// Clamps a value into the interval, projecting values outside of it to the nearest bound.
+    /// The value of an excluded bound is returned as well, even though it lies outside the interval,
+    /// as it is the closest value that can be represented in general.
+    /// 
+    /// # Arguments
+    /// * `value` - The value to clamp
+    /// 
+    /// # Returns
+    /// The value itself if it lies between the bounds, the value of the nearest bound otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed(3, 7);
+    /// assert_eq!(interval.clamp_value(0), 3);
+    /// assert_eq!(interval.clamp_value(5), 5);
+    /// assert_eq!(interval.clamp_value(10), 7);
+    /// ```
+    /// 
+    pub fn clamp_value(&self, value: T) -> T {
+        if value < *self.left.value() {
+            self.left.value().clone()
+        } else if value > *self.right.value() {
+            self.right.value().clone()
+        } else {
+            value
+        }
+    }
+
+    /// Saturates the interval into the range `[min, max]`, clipping any bound lying outside of it.
+    /// A clipped bound is set to the included range limit, so an interval straddling `max`
+    /// gets `Included(max)` as its right bound. This is the intersection with `[min, max]`.
+    /// 
+    /// # Arguments
+    /// * `min` - The smallest value of the range
+    /// * `max` - The greatest value of the range
+    /// 
+    /// # Returns
+    /// `Some(AtomicInterval)` with its bounds clipped into the range, or `None` if the interval
+    /// lies entirely outside of it or `min > max`
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::open(-10, 300);
+    /// assert_eq!(interval.saturate(0, 255), Some(AtomicInterval::closed(0, 255)));
+    /// assert_eq!(interval.saturate(400, 500), None);
+    /// ```
+    /// 
+    pub fn saturate(&self, min: T, max: T) -> Option<Self> {
+        let range = AtomicInterval::new(Bound::Included(min), Bound::Included(max)).ok()?;
+        self.intersect(&range)
+    }
+
+    /// Clamps the interval to a set of allowed regions, keeping its parts lying within any of them.
+    /// This is the intersection of the interval with the set, see [`IntervalSet::intersect_atom`].
+    /// 
+    /// # Arguments
+    /// * `allowed` - The set of allowed regions
+    /// 
+    /// # Returns
+    /// A new `IntervalSet` holding the points of the interval that lie within `allowed`
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, IntervalSet};
+    /// 
+    /// let allowed = IntervalSet::from(vec![AtomicInterval::closed(2, 4), AtomicInterval::closed(7, 12)]);
+    /// let clamped = AtomicInterval::closed(1, 10).clamp_to_set(&allowed);
+    /// assert_eq!(clamped.intervals, vec![AtomicInterval::closed(2, 4), AtomicInterval::closed(7, 10)]);
+    /// ```
+    /// 
+    pub fn clamp_to_set(&self, allowed: &IntervalSet<T>) -> IntervalSet<T> {
+        allowed.intersect_atom(self)
+    }
+
+    /// Computes the intersection of the interval with an interval set.
+    /// This is an alias of [`AtomicInterval::clamp_to_set`], complementing [`IntervalSet::intersect_atom`].
+    /// 
+    /// # Arguments
+    /// * `set` - The interval set to intersect with
+    /// 
+    /// # Returns
+    /// A new `IntervalSet` holding the pieces of the interval that lie within `set`
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, IntervalSet};
+    /// 
+    /// let set = IntervalSet::from(vec![AtomicInterval::closed(2, 5), AtomicInterval::closed(10, 12)]);
+    /// let pieces = AtomicInterval::closed(0, 11).intersection_with_set(&set);
+    /// assert_eq!(pieces.intervals, vec![AtomicInterval::closed(2, 5), AtomicInterval::closed(10, 11)]);
+    /// ```
+    /// 
+    pub fn intersection_with_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        self.clamp_to_set(set)
+    }
+
+    /// Extends the interval to cover a value, moving the nearest bound onto it.
+    /// The moved bound becomes included, so that the value lies within the result.
+    /// 
+    /// # Arguments
+    /// * `value` - The value to cover
+    /// 
+    /// # Returns
+    /// The smallest `AtomicInterval` containing both the interval and the value,
+    /// which is a copy of the interval if it already contains the value
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed(1, 5);
+    /// assert_eq!(interval.extend_to(8), AtomicInterval::closed(1, 8));
+    /// assert_eq!(interval.extend_to(0), AtomicInterval::closed(0, 5));
+    /// assert_eq!(interval.extend_to(3), interval);
+    /// ```
+    /// 
+    pub fn extend_to(&self, value: T) -> Self {
+        if self.contains(&value) {
+            self.clone()
+        } else if value <= *self.left.value() {
+            AtomicInterval { left: Bound::Included(value), right: self.right.clone() }
+        } else if value >= *self.right.value() {
+            AtomicInterval { left: self.left.clone(), right: Bound::Included(value) }
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Splits the interval in two at a given value.
+    /// The left piece excludes the cut value and the right piece includes it.
+    /// 
+    /// # Arguments
+    /// * `value` - The value at which to cut the interval
+    /// 
+    /// # Returns
+    /// A tuple `(left, right)` with the pieces before and after `value`.
+    /// If `value` lies at or before the left endpoint, the whole interval is returned as `(None, Some(self))`.
+    /// If `value` lies at or after the right endpoint, the whole interval is returned as `(Some(self), None)`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed(1, 10);
+    /// let (left, right) = interval.split_at(&4);
+    /// assert_eq!(left, Some(AtomicInterval::closed_open(1, 4)));
+    /// assert_eq!(right, Some(AtomicInterval::closed(4, 10)));
+    /// ```
+    /// 
+    pub fn split_at(&self, value: &T) -> (Option<Self>, Option<Self>) {
+        if value <= self.left.value() {
+            return (None, Some(self.clone()));
+        } else if value >= self.right.value() {
+            return (Some(self.clone()), None);
+        }
+
+        let left_interval = AtomicInterval { left: self.left.clone(), right: Bound::Excluded(value.clone()) };
+        let right_interval = AtomicInterval { left: Bound::Included(value.clone()), right: self.right.clone() };
+        (Some(left_interval), Some(right_interval))
+    }
+}
+
+/// A collection of methods for measuring numeric intervals.
+impl <T: Clone + PartialOrd + Sub> AtomicInterval<T> {
+    /// Computes the length of the interval, ignoring the inclusivity of its bounds.
+    /// 
+    /// # Returns
+    /// The difference between the right and left bound values
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed_open(2, 9);
+    /// assert_eq!(interval.length(), 7);
+    /// assert_eq!(AtomicInterval::point(4).length(), 0);
+    /// ```
+    /// 
+    pub fn length(&self) -> T::Output {
+        self.right.value().clone() - self.left.value().clone()
+    }
+
+    /// Computes the length of the overlap between the interval and another interval.
+    /// This is the length of their intersection, computed without building it.
+    /// Intervals sharing a single included point overlap with a length of zero.
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to measure the overlap with
+    /// 
+    /// # Returns
+    /// `Some(length)` of the intersection if the intervals overlap, `None` if they are disjoint
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval1 = AtomicInterval::closed(1, 5);
+    /// let interval2 = AtomicInterval::closed(3, 9);
+    /// assert_eq!(interval1.overlap_length(&interval2), Some(2));
+    /// assert_eq!(interval1.overlap_length(&AtomicInterval::closed(6, 9)), None);
+    /// ```
+    /// 
+    pub fn overlap_length(&self, other: &Self) -> Option<T::Output> {
+        if self.is_disjoint(other) {
+            return None;
+        }
+        let left = match self.left.cmp_as_left(&other.left) {
+            Ordering::Less => other.left.value(),
+            _ => self.left.value(),
+        };
+        let right = match self.right.cmp_as_right(&other.right) {
+            Ordering::Greater => other.right.value(),
+            _ => self.right.value(),
+        };
+        Some(right.clone() - left.clone())
+    }
+
+    /// Computes the distance from a value to the interval.
+    /// The distance is measured against the closure of the interval, so a value lying on an
+    /// excluded bound is at distance zero.
+    /// 
+    /// # Arguments
+    /// * `value` - The value to measure the distance from
+    /// 
+    /// # Returns
+    /// Zero if the value lies within the closure of the interval, otherwise the gap to the nearest bound
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed(3, 7);
+    /// assert_eq!(interval.distance_to(&5), 0);
+    /// assert_eq!(interval.distance_to(&1), 2);
+    /// assert_eq!(interval.distance_to(&10), 3);
+    /// ```
+    /// 
+    pub fn distance_to(&self, value: &T) -> T::Output {
+        if value < self.left.value() {
+            self.left.value().clone() - value.clone()
+        } else if value > self.right.value() {
+            value.clone() - self.right.value().clone()
+        } else {
+            value.clone() - value.clone()
+        }
+    }
+}
+
+/// A collection of constructors for creating numeric intervals.
+impl <T: Clone + PartialOrd + Add<Output = T> + Sub<Output = T>> AtomicInterval<T> {
+    /// Creates a closed interval spanning a radius on both sides of a center value.
+    /// A radius of zero produces the point interval of the center.
+    /// 
+    /// # Arguments
+    /// * `center` - The value at the center of the interval
+    /// * `radius` - The distance from the center to each bound, which must not be negative
+    /// 
+    /// # Returns
+    /// A new `AtomicInterval` equal to `[center - radius, center + radius]`
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// assert_eq!(AtomicInterval::from_center_radius(5, 2), AtomicInterval::closed(3, 7));
+    /// assert_eq!(AtomicInterval::from_center_radius(5, 0), AtomicInterval::point(5));
+    /// ```
+    /// 
+    pub fn from_center_radius(center: T, radius: T) -> Self {
+        if radius < radius.clone() - radius.clone() {
+            panic!("The following condition must be valid: `radius >= 0`");
+        }
+        AtomicInterval::point(center).expand(radius)
+    }
+}
+
+/// A collection of methods for resizing numeric intervals.
+impl <T: Clone + PartialOrd + Add<Output = T> + Sub<Output = T>> AtomicInterval<T> {
+    /// Expands the interval by a margin on both sides.
+    /// The margin is subtracted from the left bound and added to the right bound, preserving inclusivity.
+    /// 
+    /// # Arguments
+    /// * `margin` - The amount to expand each side by, which must not be negative
+    /// 
+    /// # Returns
+    /// A new `AtomicInterval` widened by `margin` on each side
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed_open(10, 20);
+    /// assert_eq!(interval.expand(5), AtomicInterval::closed_open(5, 25));
+    /// ```
+    /// 
+    pub fn expand(&self, margin: T) -> Self {
+        if margin < margin.clone() - margin.clone() {
+            panic!("The following condition must be valid: `margin >= 0`");
+        }
+        AtomicInterval {
+            left: self.left.clone().map(|value| value - margin.clone()),
+            right: self.right.clone().map(|value| value + margin.clone()),
+        }
+    }
+
+    /// Shrinks the interval by a margin on both sides.
+    /// The margin is added to the left bound and subtracted from the right bound, preserving inclusivity.
+    /// 
+    /// # Arguments
+    /// * `margin` - The amount to shrink each side by
+    /// 
+    /// # Returns
+    /// `Some(AtomicInterval)` narrowed by `margin` on each side, or `None` if the interval would collapse
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed(10, 20);
+    /// assert_eq!(interval.shrink(5), Some(AtomicInterval::point(15)));
+    /// assert_eq!(interval.shrink(6), None);
+    /// ```
+    /// 
+    pub fn shrink(&self, margin: T) -> Option<Self> {
+        AtomicInterval::new(
+            self.left.clone().map(|value| value + margin.clone()),
+            self.right.clone().map(|value| value - margin.clone()),
+        ).ok()
+    }
+}
+
+/// A collection of methods for transforming numeric intervals.
+impl <T: Clone + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> AtomicInterval<T> {
+    /// Scales the interval by a factor around an origin.
+    /// Each bound is moved to `origin + (bound - origin) * factor`, preserving inclusivity.
+    /// Bounds below the origin are moved to `origin - (origin - bound) * factor` instead,
+    /// so that no intermediate value is negative, which would underflow for unsigned types.
+    /// 
+    /// # Arguments
+    /// * `factor` - The scaling factor, which must be positive (and not NaN) so that the bounds stay ordered
+    /// * `origin` - The value that stays fixed while scaling
+    /// 
+    /// # Returns
+    /// A new `AtomicInterval` with its bounds scaled around `origin`
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed_open(2, 4);
+    /// assert_eq!(interval.scale(2, 0), AtomicInterval::closed_open(4, 8));
+    /// assert_eq!(interval.scale(3, 3), AtomicInterval::closed_open(0, 6));
+    /// assert_eq!(AtomicInterval::closed(2u32, 4).scale(2, 3), AtomicInterval::closed(1, 5));
+    /// ```
+    /// 
+    pub fn scale(&self, factor: T, origin: T) -> Self {
+        let zero = factor.clone() - factor.clone();
+        if factor.partial_cmp(&zero) != Some(Ordering::Greater) {
+            panic!("The following condition must be valid: `factor > 0`");
+        }
+        let scale = |value: T| {
+            if value >= origin {
+                origin.clone() + (value - origin.clone()) * factor.clone()
+            } else {
+                origin.clone() - (origin.clone() - value) * factor.clone()
+            }
+        };
+        AtomicInterval {
+            left: self.left.clone().map(scale),
+            right: self.right.clone().map(scale),
+        }
+    }
+}
+
+/// A collection of methods for mirroring numeric intervals.
+impl <T: Clone + PartialOrd + Add<Output = T> + Sub<Output = T>> AtomicInterval<T> {
+    /// Reflects the interval about an axis, mapping each value `x` to `2 * axis - x`.
+    /// Reflecting swaps the ends of the interval, so the new left bound takes the inclusivity
+    /// of the old right bound and vice versa. Values above the axis are mapped to `axis - (x - axis)`,
+    /// so that no intermediate value is negative, which would underflow for unsigned types.
+    /// 
+    /// # Arguments
+    /// * `axis` - The value that stays fixed while reflecting
+    /// 
+    /// # Returns
+    /// A new `AtomicInterval` mirroring the interval about `axis`
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// assert_eq!(AtomicInterval::closed(1, 4).reflect(0), AtomicInterval::closed(-4, -1));
+    /// assert_eq!(AtomicInterval::closed_open(1, 4).reflect(5), AtomicInterval::open_closed(6, 9));
+    /// assert_eq!(AtomicInterval::closed(4u32, 5).reflect(3), AtomicInterval::closed(1, 2));
+    /// ```
+    /// 
+    pub fn reflect(&self, axis: T) -> Self {
+        let reflect = |value: T| {
+            if value <= axis {
+                axis.clone() + (axis.clone() - value)
+            } else {
+                axis.clone() - (value - axis.clone())
+            }
+        };
+        AtomicInterval {
+            left: self.right.clone().map(reflect),
+            right: self.left.clone().map(reflect),
+        }
+    }
+}
+
+/// A collection of methods for dividing numeric intervals.
+impl <T: Clone + PartialOrd + Add<Output = T> + Sub<Output = T> + Div<Output = T> + TryFrom<usize>> AtomicInterval<T> {
+    /// Divides the interval into `n` contiguous sub-intervals of equal width.
+    /// Every sub-interval but the last one is left-closed and right-open, so that they do not
+    /// overlap. The first and last sub-intervals keep the inclusivity of the interval's bounds.
+    /// When the width does not divide evenly (e.g. for integers), the last sub-interval absorbs
+    /// the remainder, and if the interval is too narrow for `n` non-empty pieces fewer are returned.
+    /// If `n` is not representable in `T`, such as `1000` for `u8`, the interval is split into
+    /// pieces as wide as one instead. Floating-point types do not implement `TryFrom<usize>`,
+    /// so `AtomicInterval<f64>` is split with [`AtomicInterval::split_into_f64`].
+    /// 
+    /// # Arguments
+    /// * `n` - The number of sub-intervals
+    /// 
+    /// # Returns
+    /// A `Vec` of contiguous `AtomicInterval` covering the interval, empty if `n == 0`
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// assert_eq!(AtomicInterval::closed(0, 9).split_into(3), vec![
+    ///     AtomicInterval::closed_open(0, 3),
+    ///     AtomicInterval::closed_open(3, 6),
+    ///     AtomicInterval::closed(6, 9),
+    /// ]);
+    /// assert_eq!(AtomicInterval::closed(0u8, 255).split_into(1000).len(), 255);
+    /// ```
+    /// 
+    pub fn split_into(&self, n: usize) -> Vec<Self> {
+        if n == 0 {
+            return vec![];
+        }
+
+        let length = self.length();
+        let zero = length.clone() - length.clone();
+        let count = T::try_from(n).unwrap_or(length.clone());
+        if count <= zero {
+            return vec![self.clone()];
+        }
+        let width = length / count;
+
+        let mut atoms = Vec::new();
+        let mut left = self.left.clone();
+        let mut boundary = self.left.value().clone();
+        let right = self.right.value();
+        for _ in 1..n {
+            // Stop once the remaining pieces would be empty, which also keeps `boundary` within `T`.
+            if width <= zero || right.clone() - boundary.clone() <= width {
+                break;
+            }
+            boundary = boundary + width.clone();
+            atoms.push(AtomicInterval { left, right: Bound::Excluded(boundary.clone()) });
+            left = Bound::Included(boundary.clone());
+        }
+        if let Ok(atom) = AtomicInterval::new(left, self.right.clone()) {
+            atoms.push(atom);
+        }
+
+        atoms
+    }
+}
+
+/// A collection of methods for locating points within numeric intervals.
+impl <T: Clone + PartialOrd + Add<Output = T> + Sub<Output = T> + Div<Output = T> + From<u8>> AtomicInterval<T> {
+    /// Computes the midpoint of the interval, halfway between the values of its bounds.
+    /// Inclusivity is ignored, and integer midpoints are rounded towards the left bound.
+    /// The midpoint is computed as `left + (right - left) / 2`, which does not overflow as long as
+    /// the length of the interval is representable in `T`.
+    /// 
+    /// # Returns
+    /// The value halfway between the left and right bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// assert_eq!(AtomicInterval::closed(0, 10).midpoint(), 5);
+    /// assert_eq!(AtomicInterval::open(1.0, 2.0).midpoint(), 1.5);
+    /// ```
+    /// 
+    pub fn midpoint(&self) -> T {
+        let left = self.left.value().clone();
+        let right = self.right.value().clone();
+        left.clone() + (right - left) / T::from(2)
+    }
+}
+
+/// A collection of overflow-aware methods for measuring numeric intervals.
+impl <T: Clone + PartialOrd + CheckedOps> AtomicInterval<T> {
+    /// Computes the length of the interval like [`AtomicInterval::length`], reporting overflow.
+    /// 
+    /// # Returns
+    /// `Some(length)` of the interval, or `None` if it is not representable in `T`
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// assert_eq!(AtomicInterval::closed(1, 5).checked_length(), Some(4));
+    /// assert_eq!(AtomicInterval::closed(i32::MIN, i32::MAX).checked_length(), None);
+    /// ```
+    /// 
+    pub fn checked_length(&self) -> Option<T> {
+        self.right.value().checked_sub(self.left.value())
+    }
+}
+
+/// A collection of overflow-aware methods for locating points within numeric intervals.
+impl <T: Clone + PartialOrd + Div<Output = T> + From<u8> + CheckedOps> AtomicInterval<T> {
+    /// Computes the midpoint of the interval like [`AtomicInterval::midpoint`], reporting overflow.
+    /// 
+    /// # Returns
+    /// `Some(midpoint)` of the interval, or `None` if its length is not representable in `T`
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// assert_eq!(AtomicInterval::closed(0, 10).checked_midpoint(), Some(5));
+    /// assert_eq!(AtomicInterval::closed(i32::MIN, i32::MAX).checked_midpoint(), None);
+    /// ```
+    /// 
+    pub fn checked_midpoint(&self) -> Option<T> {
+        let half = self.checked_length()? / T::from(2);
+        self.left.value().checked_add(&half)
+    }
+}
+
+/// A collection of methods for intervals over discrete values, such as integers.
+impl <T: Clone + PartialOrd + Discrete> AtomicInterval<T> {
+    /// Checks if the interval is adjacent to another interval, when only discrete values are considered.
+    /// Two intervals are adjacent if they do not overlap and no value lies between them,
+    /// so that `[1, 5]` and `[6, 9]` are adjacent over integers, while they are not under [`AtomicInterval::is_adjacent`].
+    /// 
+    /// # Arguments
+    /// * `other` - The other interval to check if it is adjacent to the current interval
+    /// 
+    /// # Returns
+    /// `true` if the current interval is adjacent to the other interval over discrete values, `false` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval1 = AtomicInterval::closed(1, 5);
+    /// assert!(interval1.is_adjacent_discrete(&AtomicInterval::closed(6, 9)));
+    /// assert!(!interval1.is_adjacent_discrete(&AtomicInterval::closed(7, 9)));
+    /// ```
+    /// 
+    pub fn is_adjacent_discrete(&self, other: &Self) -> bool {
+        if self.is_overlapping(other) {
+            return false;
+        }
+        self.is_followed_by(other) || other.is_followed_by(self)
+    }
+
+    /// Computes the union of two overlapping or adjacent intervals, when only discrete values are considered.
+    /// Unlike [`AtomicInterval::union`], intervals with no value between them are merged as well.
+    /// 
+    /// # Arguments
+    /// * `a` - The first interval to union
+    /// * `b` - The second interval to union
+    /// 
+    /// # Returns
+    /// A `Vec` containing the union of the two intervals if they are overlapping or adjacent over discrete values,
+    /// an empty `Vec` otherwise
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval1 = AtomicInterval::closed(1, 5);
+    /// let interval2 = AtomicInterval::closed(6, 9);
+    /// assert_eq!(AtomicInterval::union_discrete(&interval1, &interval2), vec![AtomicInterval::closed(1, 9)]);
+    /// assert!(AtomicInterval::union(&interval1, &interval2).is_empty());
+    /// ```
+    /// 
+    pub fn union_discrete(a: &AtomicInterval<T>, b: &AtomicInterval<T>) -> Vec<AtomicInterval<T>> {
+        if !a.is_overlapping(b) && !a.is_adjacent_discrete(b) {
+            return vec![];
+        }
+
+        // Keep the left bound that starts first and the right bound that ends last.
+        let left = match a.left.cmp_as_left(&b.left) {
+            Ordering::Greater => b.left.clone(),
+            _ => a.left.clone(),
+        };
+        let right = match a.right.cmp_as_right(&b.right) {
+            Ordering::Less => b.right.clone(),
+            _ => a.right.clone(),
+        };
+        vec![AtomicInterval { left, right }]
+    }
+
+    /// Converts the interval to its canonical left-closed, right-open form over discrete values.
+    /// Intervals holding the same discrete values, such as `[1, 5]`, `(0, 5]` and `(0, 6)`,
+    /// all convert to the same interval, `[1, 6)`, so they compare equal after conversion.
+    /// 
+    /// # Returns
+    /// `Some(AtomicInterval)` in left-closed, right-open form holding the same discrete values,
+    /// or `None` if the interval holds no discrete value, such as `(4, 5)` over integers,
+    /// or if the right bound includes the greatest value of `T`, which has no successor to exclude
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// assert_eq!(AtomicInterval::closed(1, 5).to_half_open(), Some(AtomicInterval::closed_open(1, 6)));
+    /// assert_eq!(AtomicInterval::open(1, 5).to_half_open(), Some(AtomicInterval::closed_open(2, 5)));
+    /// assert_eq!(AtomicInterval::open(4, 5).to_half_open(), None);
+    /// assert_eq!(AtomicInterval::closed(1u8, 255).to_half_open(), None);
+    /// ```
+    /// 
+    pub fn to_half_open(&self) -> Option<Self> {
+        let left = match &self.left {
+            Bound::Included(value) => value.clone(),
+            Bound::Excluded(value) => value.successor()?,
+        };
+        let right = match &self.right {
+            Bound::Included(value) => value.successor()?,
+            Bound::Excluded(value) => value.clone(),
+        };
+        AtomicInterval::new(Bound::Included(left), Bound::Excluded(right)).ok()
+    }
+
+    /// Checks if the first value after the interval is the first value of another interval.
+    fn is_followed_by(&self, other: &Self) -> bool {
+        let next = match &self.right {
+            Bound::Included(value) => value.successor(),
+            Bound::Excluded(value) => Some(value.clone()),
+        };
+        let first = match &other.left {
+            Bound::Included(value) => Some(value.clone()),
+            Bound::Excluded(value) => value.successor(),
+        };
+        match (next, first) {
+            (Some(next), Some(first)) => next == first,
+            (_, _) => false,
+        }
+    }
+}
+
+/// A collection of methods for aligning numeric intervals to a grid.
+impl <T: Clone + PartialOrd + Add<Output = T> + Sub<Output = T> + Rem<Output = T>> AtomicInterval<T> {
+    /// Snaps the interval to a grid of multiples of `step`.
+    /// The left bound is floored and the right bound is ceiled, so the snapped interval always
+    /// contains the original one. Bounds already on a grid line stay put and inclusivity is preserved.
+    /// 
+    /// # Arguments
+    /// * `step` - The grid spacing, which must be positive
+    /// 
+    /// # Returns
+    /// A new `AtomicInterval` with bounds aligned to multiples of `step`
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::open_closed(13, 47);
+    /// assert_eq!(interval.snap(10), AtomicInterval::open_closed(10, 50));
+    /// ```
+    /// 
+    pub fn snap(&self, step: T) -> Self {
+        let zero = step.clone() - step.clone();
+        if step <= zero {
+            panic!("The following condition must be valid: `step > 0`");
+        }
+
+        // Distance from a value down to the closest grid line, also correct for negative values.
+        // Only negative remainders are shifted by `step`, so the offset never overflows.
+        let offset = |value: &T| {
+            let remainder = value.clone() % step.clone();
+            if remainder < zero {
+                remainder + step.clone()
+            } else {
+                remainder
+            }
+        };
+
+        let left_offset = offset(self.left.value());
+        let left = self.left.value().clone() - left_offset;
+
+        let right_offset = offset(self.right.value());
+        let right = if right_offset == zero {
+            self.right.value().clone()
+        } else {
+            self.right.value().clone() - right_offset + step.clone()
+        };
+
+        let left = match &self.left {
+            Bound::Included(_) => Bound::Included(left),
+            Bound::Excluded(_) => Bound::Excluded(left),
+        };
+        let right = match &self.right {
+            Bound::Included(_) => Bound::Included(right),
+            Bound::Excluded(_) => Bound::Excluded(right),
+        };
+        AtomicInterval { left, right }
+    }
+}
+
+/// A collection of methods for sampling floating-point intervals.
+impl AtomicInterval<f64> {
+    /// Divides the interval into `n` contiguous sub-intervals of equal width, like
+    /// [`AtomicInterval::split_into`] does for types implementing `TryFrom<usize>`.
+    /// Boundaries are computed as `left + i * width` rather than by repeated addition, so rounding
+    /// errors do not accumulate. An interval with zero or infinite length is returned whole.
+    /// 
+    /// # Arguments
+    /// * `n` - The number of sub-intervals
+    /// 
+    /// # Returns
+    /// A `Vec` of contiguous `AtomicInterval` covering the interval, empty if `n == 0`
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// assert_eq!(AtomicInterval::closed(0.0, 1.5).split_into_f64(2), vec![
+    ///     AtomicInterval::closed_open(0.0, 0.75),
+    ///     AtomicInterval::closed(0.75, 1.5),
+    /// ]);
+    /// ```
+    /// 
+    pub fn split_into_f64(&self, n: usize) -> Vec<Self> {
+        if n == 0 {
+            return vec![];
+        }
+
+        let left = *self.left.value();
+        let width = self.length() / n as f64;
+        if !(width > 0.0 && width.is_finite()) {
+            return vec![self.clone()];
+        }
+
+        let mut atoms = Vec::with_capacity(n);
+        let mut bound = self.left;
+        for i in 1..n {
+            let boundary = left + i as f64 * width;
+            // Skip empty pieces, which arise when the width is below the precision of the bounds.
+            if let Ok(atom) = AtomicInterval::new(bound, Bound::Excluded(boundary)) {
+                atoms.push(atom);
+                bound = Bound::Included(boundary);
+            }
+        }
+        if let Ok(atom) = AtomicInterval::new(bound, self.right) {
+            atoms.push(atom);
+        }
+
+        atoms
+    }
+
+    /// Iterates over regularly-spaced samples of the interval, starting at its left bound.
+    /// Samples are computed as `left + i * step` rather than by repeated addition, so rounding
+    /// errors do not accumulate. Excluded bounds are respected: an excluded left bound is not
+    /// sampled, and sampling stops before an excluded right bound. Sampling also stops before the
+    /// first sample that is not finite, so an interval reaching infinity is not sampled forever.
+    /// 
+    /// # Arguments
+    /// * `step` - The spacing between samples; the iterator is empty unless it is positive and finite
+    /// 
+    /// # Returns
+    /// An iterator over the samples lying within the interval, in ascending order
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let interval = AtomicInterval::closed(0.0, 1.0);
+    /// assert_eq!(interval.step_by(0.25).collect::<Vec<_>>(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// assert_eq!(interval.step_by(0.0).count(), 0);
+    /// ```
+    /// 
+    pub fn step_by(&self, step: f64) -> impl Iterator<Item = f64> + '_ {
+        let left = *self.left.value();
+        let first = match self.left {
+            Bound::Included(_) => 0u64,
+            Bound::Excluded(_) => 1u64,
+        };
+        // A non-positive or NaN step, or an unbounded left end, would never make progress,
+        // and an infinite step makes every sample but the first NaN.
+        let valid = step > 0.0 && step.is_finite() && left.is_finite();
+
+        (first..)
+            .take_while(move |_| valid)
+            .map(move |i| left + i as f64 * step)
+            .take_while(move |sample| sample.is_finite() && self.contains(sample))
+    }
+}
+
+/// A collection of methods for intervals over characters.
+impl AtomicInterval<char> {
+    /// Iterates over the characters of the interval in order, respecting the inclusivity of its bounds.
+    /// The surrogate code points, which are not valid characters, are skipped.
+    /// 
+    /// # Returns
+    /// An iterator over the characters lying within the interval, in ascending order
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// 
+    /// let letters: String = AtomicInterval::closed('a', 'e').chars().collect();
+    /// assert_eq!(letters, "abcde");
+    /// ```
+    /// 
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        let first = match self.left {
+            Bound::Included(c) => Some(c),
+            Bound::Excluded(c) => c.successor(),
+        };
+        core::iter::successors(first, |c| c.successor()).take_while(move |c| self.contains(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_with_each_bound_combination() {
+        let interval = AtomicInterval::new(Bound::Included(1), Bound::Included(5));
+        assert_eq!(interval, Ok(AtomicInterval::closed(1, 5)));
+        let interval = AtomicInterval::new(Bound::Excluded(1), Bound::Excluded(5));
+        assert_eq!(interval, Ok(AtomicInterval::open(1, 5)));
+        let interval = AtomicInterval::new(Bound::Included(1), Bound::Excluded(5));
+        assert_eq!(interval, Ok(AtomicInterval::closed_open(1, 5)));
+        let interval = AtomicInterval::new(Bound::Excluded(1), Bound::Included(5));
+        assert_eq!(interval, Ok(AtomicInterval::open_closed(1, 5)));
+        let interval = AtomicInterval::new(Bound::Included(3), Bound::Included(3));
+        assert_eq!(interval, Ok(AtomicInterval::point(3)));
+    }
+
+    #[test]
+    fn test_new_with_invalid_bounds() {
+        let interval = AtomicInterval::new(Bound::Included(5), Bound::Included(1));
+        assert_eq!(interval, Err(IntervalError::InvalidBounds));
+        let interval = AtomicInterval::new(Bound::Included(3), Bound::Excluded(3));
+        assert_eq!(interval, Err(IntervalError::InvalidBounds));
+    }
+
+    #[test]
+    fn test_open_interval() {
+        let interval = AtomicInterval::open(1, 5);
+        assert_eq!(interval.left, Bound::Excluded(1));
+        assert_eq!(interval.right, Bound::Excluded(5));
+    }
+
+    #[test]
+    fn test_closed_interval() {
+        let interval = AtomicInterval::closed(1, 5);
+        assert_eq!(interval.left, Bound::Included(1));
+        assert_eq!(interval.right, Bound::Included(5));
+    }
+
+    #[test]
+    fn test_open_closed_interval() {
+        let interval = AtomicInterval::open_closed(1, 5);
+        assert_eq!(interval.left, Bound::Excluded(1));
+        assert_eq!(interval.right, Bound::Included(5));
+    }
+
+    #[test]
+    fn test_closed_open_interval() {
+        let interval = AtomicInterval::closed_open(1, 5);
+        assert_eq!(interval.left, Bound::Included(1));
+        assert_eq!(interval.right, Bound::Excluded(5));
+    }
+
+    #[test]
+    fn test_point_interval() {
+        let interval = AtomicInterval::point(1);
+        assert_eq!(interval.left, Bound::Included(1));
+        assert_eq!(interval.right, Bound::Included(1));
+    }
+
+    #[test]
+    fn test_is_bounded() {
+        let intervals = vec![
+            AtomicInterval::open(1, 5),
+            AtomicInterval::closed(1, 5),
+            AtomicInterval::open_closed(1, 5),
+            AtomicInterval::closed_open(1, 5),
+            AtomicInterval::point(1),
+        ];
+        for interval in intervals {
+            assert!(interval.is_bounded());
+            assert!(!interval.is_unbounded());
+        }
+    }
+
+    #[test]
+    fn test_contains() {
+        let interval = AtomicInterval::open_closed(1, 5);
+        assert!(!interval.contains(&1));
+        assert!(interval.contains(&3));
+        assert!(interval.contains(&5));
+        assert!(!interval.contains(&6));
+    }
+
+    #[test]
+    fn test_with_left() {
+        let interval = AtomicInterval::closed(1, 10);
+        assert_eq!(interval.with_left(Bound::Included(3)), Some(AtomicInterval::closed(3, 10)));
+        assert_eq!(interval.with_left(Bound::Excluded(10)), None);
+        assert_eq!(interval.with_left(Bound::Included(20)), None);
+    }
+
+    #[test]
+    fn test_with_right() {
+        let interval = AtomicInterval::closed(1, 10);
+        assert_eq!(interval.with_right(Bound::Excluded(7)), Some(AtomicInterval::closed_open(1, 7)));
+        assert_eq!(interval.with_right(Bound::Included(1)), Some(AtomicInterval::point(1)));
+        assert_eq!(interval.with_right(Bound::Included(0)), None);
+    }
+
+    #[test]
+    fn test_closure() {
+        assert_eq!(AtomicInterval::open_closed(1, 5).closure(), AtomicInterval::closed(1, 5));
+        assert_eq!(AtomicInterval::open(1, 5).closure(), AtomicInterval::closed(1, 5));
+        assert_eq!(AtomicInterval::point(3).closure(), AtomicInterval::point(3));
+    }
+
+    #[test]
+    fn test_interior() {
+        assert_eq!(AtomicInterval::closed(1, 5).interior(), Some(AtomicInterval::open(1, 5)));
+        assert_eq!(AtomicInterval::closed_open(1, 5).interior(), Some(AtomicInterval::open(1, 5)));
+        assert_eq!(AtomicInterval::point(3).interior(), None);
+    }
+
+    #[test]
+    fn test_equivalent_points() {
+        let interval = AtomicInterval::new(Bound::Included(3), Bound::Included(3)).unwrap();
+        assert!(interval.equivalent(&AtomicInterval::point(3)));
+        assert!(!interval.equivalent(&AtomicInterval::point(4)));
+    }
+
+    #[test]
+    fn test_equivalent_intervals() {
+        let interval = AtomicInterval::closed(1.0, 5.0);
+        assert!(interval.equivalent(&AtomicInterval::closed(1.0, 5.0)));
+        assert!(!interval.equivalent(&AtomicInterval::closed_open(1.0, 5.0)));
+        assert!(!interval.equivalent(&AtomicInterval::open_closed(1.0, 5.0)));
+    }
+
+    #[test]
+    fn test_equivalent_empty_intervals() {
+        let empty1 = AtomicInterval { left: Bound::Excluded(3), right: Bound::Excluded(3) };
+        let empty2 = AtomicInterval { left: Bound::Included(5), right: Bound::Included(1) };
+        assert!(empty1.equivalent(&empty2));
+        assert!(!empty1.equivalent(&AtomicInterval::point(3)));
+    }
+
+    #[test]
+    fn test_is_overlapping() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(4, 6);
+        assert!(interval1.is_overlapping(&interval2));
+    }
+
+    #[test]
+    fn test_is_overlapping_open_intervals_sharing_endpoint() {
+        let interval1 = AtomicInterval::open(1, 5);
+        let interval2 = AtomicInterval::open(5, 9);
+        assert!(!interval1.is_overlapping(&interval2));
+        assert!(interval1.is_disjoint(&interval2));
+    }
+
+    #[test]
+    fn test_is_overlapping_superset() {
+        let interval1 = AtomicInterval::closed(2, 4);
+        let interval2 = AtomicInterval::open(1, 5);
+        assert!(interval1.is_overlapping(&interval2));
+        assert!(interval2.is_overlapping(&interval1));
+    }
+
+    #[test]
+    fn test_is_overlapping_is_complement_of_is_disjoint() {
+        fn build(style: usize, left: i32, right: i32) -> AtomicInterval<i32> {
+            match style {
+                0 => AtomicInterval::closed(left, right),
+                1 => AtomicInterval::open(left, right),
+                2 => AtomicInterval::closed_open(left, right),
+                _ => AtomicInterval::open_closed(left, right),
+            }
+        }
+
+        // Membership of `x / 2`, so that half-integer points between endpoints are probed too.
+        fn contains_half(interval: &AtomicInterval<i32>, x: i32) -> bool {
+            let left = match interval.left() {
+                Bound::Included(l) => x >= 2 * l,
+                Bound::Excluded(l) => x > 2 * l,
+            };
+            let right = match interval.right() {
+                Bound::Included(r) => x <= 2 * r,
+                Bound::Excluded(r) => x < 2 * r,
+            };
+            left && right
+        }
+
+        let endpoints = [(0, 2), (1, 3), (2, 4), (3, 5), (0, 5)];
+        let mut intervals = Vec::new();
+        for style in 0..4 {
+            for &(left, right) in &endpoints {
+                intervals.push(build(style, left, right));
+            }
+        }
+        for value in 0..=5 {
+            intervals.push(AtomicInterval::point(value));
+        }
+
+        for a in &intervals {
+            for b in &intervals {
+                let expected = (-2..=12).any(|x| contains_half(a, x) && contains_half(b, x));
+                assert_eq!(a.is_overlapping(b), expected, "is_overlapping({:?}, {:?})", a, b);
+                assert_eq!(a.is_disjoint(b), !expected, "is_disjoint({:?}, {:?})", a, b);
+                assert_eq!(a.is_overlapping(b), b.is_overlapping(a));
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_adjacent() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::open_closed(5, 10);
+        assert!(interval1.is_adjacent(&interval2));
+    }
+
+    #[test]
+    fn test_is_disjoint() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(6, 10);
+        assert!(interval1.is_disjoint(&interval2));
+    }
+
+    #[test]
+    fn test_is_subset() {
+        let interval1 = AtomicInterval::closed(2, 4);
+        let interval2 = AtomicInterval::closed(1, 5);
+        assert!(interval1.is_subset(&interval2));
+    }
+
+    #[test]
+    fn test_is_superset() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(2, 4);
+        assert!(interval1.is_superset(&interval2));
+    }
+
+    #[test]
+    fn test_union_overlapping_intervals() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(4, 7);
+        let merged = AtomicInterval::union(&interval1, &interval2);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged.first().unwrap(), &AtomicInterval::closed(1, 7));
+    }
+
+    #[test]
+    fn test_union_adjacent_intervals() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(5, 7);
+        let merged = AtomicInterval::union(&interval1, &interval2);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged.first().unwrap(), &AtomicInterval::closed(1, 7));
+    }
+
+    #[test]
+    fn test_union_prefers_included_bounds_on_ties() {
+        let interval1 = AtomicInterval::open_closed(1, 5);
+        let interval2 = AtomicInterval::closed_open(1, 5);
+        let merged = AtomicInterval::union(&interval1, &interval2);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged.first().unwrap(), &AtomicInterval::closed(1, 5));
+    }
+
+    #[test]
+    fn test_union_disjoint_intervals() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(6, 7);
+        let merged = AtomicInterval::union(&interval1, &interval2);
+        assert_eq!(merged.len(), 0);
+    }
+
+    #[test]
+    fn test_intersection_between_two_overlapping_intervals() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(3, 7);
+        let intersection = interval1.intersection(&interval2);
+        assert_eq!(intersection.len(), 1);
+        assert_eq!(intersection.first().unwrap(), &AtomicInterval::closed(3, 5));
+    }
+
+    #[test]
+    fn test_intersection_between_two_disjoint_intervals() {
+        let interval1 = AtomicInterval::closed(1, 3);
+        let interval2 = AtomicInterval::closed(4, 7);
+        let intersection = interval1.intersection(&interval2);
+        assert_eq!(intersection.len(), 0);
+    }
+
+    #[test]
+    fn test_intersection_between_two_adjacent_intervals() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::open(5, 7);
+        let intersection = interval1.intersection(&interval2);
+        assert_eq!(intersection.len(), 0);
+    }
+
+    #[test]
+    fn test_intersection_prefers_excluded_bounds_on_ties() {
+        let interval1 = AtomicInterval::open_closed(1, 5);
+        let interval2 = AtomicInterval::closed_open(1, 5);
+        let intersection = interval1.intersection(&interval2);
+        assert_eq!(intersection.len(), 1);
+        assert_eq!(intersection.first().unwrap(), &AtomicInterval::open(1, 5));
+    }
+
+    #[test]
+    fn test_difference_between_two_overlapping_intervals() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(3, 7);
+        let difference = interval1.difference(&interval2);
+        assert_eq!(difference.len(), 1);
+        assert_eq!(difference[0], AtomicInterval::closed_open(1, 3));
+    }
+
+    #[test]
+    fn test_difference_between_subset_and_superset_interval() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(2, 4);
+        let difference = interval1.difference(&interval2);
+        assert_eq!(difference.len(), 2);
+        assert_eq!(difference[0], AtomicInterval::closed_open(1, 2));
+        assert_eq!(difference[1], AtomicInterval::open_closed(4, 5));
+    }
+
+    #[test]
+    fn test_difference_keeps_boundary_of_open_subtrahend() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::open_closed(3, 7);
+        let difference = interval1.difference(&interval2);
+        assert_eq!(difference.len(), 1);
+        assert_eq!(difference[0], AtomicInterval::closed(1, 3));
+    }
+
+    #[test]
+    fn test_difference_keeps_single_point_remainder() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::open_closed(1, 5);
+        let difference = interval1.difference(&interval2);
+        assert_eq!(difference.len(), 1);
+        assert_eq!(difference[0], AtomicInterval::point(1));
+    }
+
+    #[test]
+    fn test_difference_between_two_disjoint_intervals() {
+        let interval1 = AtomicInterval::closed(1, 3);
+        let interval2 = AtomicInterval::closed(4, 7);
+        let difference = interval1.difference(&interval2);
+        assert_eq!(difference.len(), 1);
+        assert_eq!(difference[0], AtomicInterval::closed(1, 3));
+    }
+
+    #[test]
+    fn test_difference_between_two_adjacent_intervals() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::open(5, 7);
+        let difference = interval1.difference(&interval2);
+        assert_eq!(difference.len(), 1);
+        assert_eq!(difference[0], AtomicInterval::closed(1, 5));
+    }
+
+    #[test]
+    fn test_split_at_interior_point() {
+        let interval = AtomicInterval::open(1, 10);
+        let (left, right) = interval.split_at(&4);
+        assert_eq!(left, Some(AtomicInterval::open(1, 4)));
+        assert_eq!(right, Some(AtomicInterval::closed_open(4, 10)));
+    }
+
+    #[test]
+    fn test_split_at_boundary_points() {
+        let interval = AtomicInterval::closed(1, 10);
+        assert_eq!(interval.split_at(&1), (None, Some(interval.clone())));
+        assert_eq!(interval.split_at(&10), (Some(interval.clone()), None));
+    }
+
+    #[test]
+    fn test_split_at_outside_points() {
+        let interval = AtomicInterval::closed(1, 10);
+        assert_eq!(interval.split_at(&0), (None, Some(interval.clone())));
+        assert_eq!(interval.split_at(&11), (Some(interval.clone()), None));
+    }
+
+    #[test]
+    fn test_snap_to_grid() {
+        let interval = AtomicInterval::open_closed(13, 47);
+        let snapped = interval.snap(10);
+        assert_eq!(snapped.left, Bound::Excluded(10));
+        assert_eq!(snapped.right, Bound::Included(50));
+    }
+
+    #[test]
+    fn test_snap_keeps_bounds_on_grid_lines() {
+        let interval = AtomicInterval::closed_open(10, 47);
+        assert_eq!(interval.snap(10), AtomicInterval::closed_open(10, 50));
+        let interval = AtomicInterval::closed(15, 30);
+        assert_eq!(interval.snap(15), AtomicInterval::closed(15, 30));
+    }
+
+    #[test]
+    fn test_snap_negative_bounds() {
+        let interval = AtomicInterval::closed(-13, -7);
+        assert_eq!(interval.snap(10), AtomicInterval::closed(-20, 0));
+    }
+
+    #[test]
+    fn test_snap_with_large_step() {
+        let interval = AtomicInterval::closed(1_500_000_000i32, 1_600_000_000);
+        assert_eq!(interval.snap(2_000_000_000), AtomicInterval::closed(0, 2_000_000_000));
+        let interval = AtomicInterval::closed(-1_500_000_000i32, -1_000_000_000);
+        assert_eq!(interval.snap(2_000_000_000), AtomicInterval::closed(-2_000_000_000, 0));
+        let interval = AtomicInterval::closed_open(100u8, 120);
+        assert_eq!(interval.snap(200), AtomicInterval::closed_open(0, 200));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_snap_non_positive_step() {
+        AtomicInterval::closed(1, 5).snap(0);
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let interval = AtomicInterval::closed_open(1, 5);
+        assert_eq!(interval.contains_point(&1), interval.contains(&1));
+        assert_eq!(interval.contains_point(&5), interval.contains(&5));
+    }
+
+    #[test]
+    fn test_length() {
+        assert_eq!(AtomicInterval::open(1, 5).length(), 4);
+        assert_eq!(AtomicInterval::closed(-2.5, 2.5).length(), 5.0);
+        assert_eq!(AtomicInterval::point(3).length(), 0);
+    }
+
+    #[test]
+    fn test_distance_to_inside() {
+        let interval = AtomicInterval::closed(3, 7);
+        assert_eq!(interval.distance_to(&3), 0);
+        assert_eq!(interval.distance_to(&5), 0);
+    }
+
+    #[test]
+    fn test_distance_to_excluded_bound() {
+        let interval = AtomicInterval::open(3, 7);
+        assert_eq!(interval.distance_to(&3), 0);
+        assert_eq!(interval.distance_to(&7), 0);
+    }
+
+    #[test]
+    fn test_distance_to_left_of() {
+        let interval = AtomicInterval::closed(3.0, 7.0);
+        assert_eq!(interval.distance_to(&1.5), 1.5);
+    }
+
+    #[test]
+    fn test_distance_to_right_of() {
+        let interval = AtomicInterval::closed(3, 7);
+        assert_eq!(interval.distance_to(&10), 3);
+    }
+
+    #[test]
+    fn test_relate_covers_all_allen_relations() {
+        let reference = AtomicInterval::closed(4, 8);
+        let cases = vec![
+            (AtomicInterval::closed(1, 2), AllenRelation::Before),
+            (AtomicInterval::closed_open(1, 4), AllenRelation::Meets),
+            (AtomicInterval::closed(2, 6), AllenRelation::Overlaps),
+            (AtomicInterval::closed(4, 6), AllenRelation::Starts),
+            (AtomicInterval::closed(5, 7), AllenRelation::During),
+            (AtomicInterval::closed(6, 8), AllenRelation::Finishes),
+            (AtomicInterval::closed(4, 8), AllenRelation::Equals),
+            (AtomicInterval::closed(2, 8), AllenRelation::FinishedBy),
+            (AtomicInterval::closed(2, 10), AllenRelation::Contains),
+            (AtomicInterval::closed(4, 10), AllenRelation::StartedBy),
+            (AtomicInterval::closed(6, 10), AllenRelation::OverlappedBy),
+            (AtomicInterval::open_closed(8, 10), AllenRelation::MetBy),
+            (AtomicInterval::closed(9, 10), AllenRelation::After),
+        ];
+        for (interval, relation) in cases {
+            assert_eq!(interval.relate(&reference), relation, "{:?}", interval);
+            assert_eq!(reference.relate(&interval), relation.inverse(), "{:?}", interval);
+        }
+    }
+
+    #[test]
+    fn test_relate_respects_inclusivity() {
+        // Sharing a single included point is an overlap.
+        let interval1 = AtomicInterval::closed(1, 4);
+        let interval2 = AtomicInterval::closed(4, 8);
+        assert_eq!(interval1.relate(&interval2), AllenRelation::Overlaps);
+
+        // Excluding the shared point on both sides leaves a gap.
+        let interval1 = AtomicInterval::open(1, 4);
+        let interval2 = AtomicInterval::open(4, 8);
+        assert_eq!(interval1.relate(&interval2), AllenRelation::Before);
+
+        // Differing inclusivity on the left bound is no longer a common start.
+        let interval1 = AtomicInterval::open_closed(4, 6);
+        let interval2 = AtomicInterval::closed(4, 8);
+        assert_eq!(interval1.relate(&interval2), AllenRelation::During);
+    }
+
+    #[test]
+    fn test_is_before() {
+        let interval1 = AtomicInterval::closed(1, 3);
+        let interval2 = AtomicInterval::closed(5, 8);
+        assert!(interval1.is_before(&interval2));
+        assert!(!interval2.is_before(&interval1));
+    }
+
+    #[test]
+    fn test_is_before_touching_intervals() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(5, 8);
+        assert!(!interval1.is_before(&interval2));
+        assert!(!interval1.is_after(&interval2));
+
+        let interval1 = AtomicInterval::closed_open(1, 5);
+        assert!(interval1.is_before(&interval2));
+    }
+
+    #[test]
+    fn test_is_after() {
+        let interval1 = AtomicInterval::open(5, 8);
+        let interval2 = AtomicInterval::closed(1, 5);
+        assert!(interval1.is_after(&interval2));
+        assert!(!interval2.is_after(&interval1));
+        assert!(!interval1.is_after(&AtomicInterval::closed(4, 6)));
+    }
+
+    #[test]
+    fn test_expand() {
+        let interval = AtomicInterval::open_closed(10, 20);
+        assert_eq!(interval.expand(5), AtomicInterval::open_closed(5, 25));
+        assert_eq!(AtomicInterval::point(3).expand(1), AtomicInterval::closed(2, 4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_expand_negative_margin() {
+        AtomicInterval::closed(10, 20).expand(-1);
+    }
+
+    #[test]
+    fn test_shrink() {
+        let interval = AtomicInterval::closed(10, 20);
+        assert_eq!(interval.shrink(3), Some(AtomicInterval::closed(13, 17)));
+        assert_eq!(interval.shrink(5), Some(AtomicInterval::point(15)));
+        assert_eq!(interval.shrink(6), None);
+        assert_eq!(AtomicInterval::closed_open(10, 20).shrink(5), None);
+    }
+
+    #[test]
+    fn test_overlap_length_of_overlapping_intervals() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::open(3, 9);
+        assert_eq!(interval1.overlap_length(&interval2), Some(2));
+        assert_eq!(interval2.overlap_length(&interval1), Some(2));
+        assert_eq!(interval1.overlap_length(&AtomicInterval::closed(2, 3)), Some(1));
+    }
+
+    #[test]
+    fn test_overlap_length_of_intervals_touching_at_a_point() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(5, 9);
+        assert_eq!(interval1.overlap_length(&interval2), Some(0));
+        let interval2 = AtomicInterval::open(5, 9);
+        assert_eq!(interval1.overlap_length(&interval2), None);
+    }
+
+    #[test]
+    fn test_overlap_length_of_disjoint_intervals() {
+        let interval1 = AtomicInterval::closed(1.0, 5.0);
+        let interval2 = AtomicInterval::closed(6.0, 9.0);
+        assert_eq!(interval1.overlap_length(&interval2), None);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        assert_eq!(AtomicInterval::closed(0, 10).midpoint(), 5);
+        assert_eq!(AtomicInterval::closed_open(0, 5).midpoint(), 2);
+        assert_eq!(AtomicInterval::point(-3).midpoint(), -3);
+        assert_eq!(AtomicInterval::open(-2.0, 3.0).midpoint(), 0.5);
+    }
+
+    #[test]
+    fn test_midpoint_does_not_overflow() {
+        assert_eq!(AtomicInterval::closed(1, i32::MAX).midpoint(), 1 + (i32::MAX - 1) / 2);
+        assert_eq!(AtomicInterval::closed(i32::MAX - 2, i32::MAX).midpoint(), i32::MAX - 1);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let interval = AtomicInterval::open_closed(2, 6);
+        assert_eq!(interval.bounds(), (&Bound::Excluded(2), &Bound::Included(6)));
+        assert_eq!(interval.into_bounds(), (Bound::Excluded(2), Bound::Included(6)));
+    }
+
+    #[test]
+    fn test_checked_length() {
+        assert_eq!(AtomicInterval::open(-3, 4).checked_length(), Some(7));
+        assert_eq!(AtomicInterval::closed(0, i32::MAX).checked_length(), Some(i32::MAX));
+        assert_eq!(AtomicInterval::closed(-1, i32::MAX).checked_length(), None);
+        assert_eq!(AtomicInterval::closed(0u8, 255).checked_length(), Some(255));
+    }
+
+    #[test]
+    fn test_checked_midpoint() {
+        assert_eq!(AtomicInterval::closed(0, 10).checked_midpoint(), Some(5));
+        assert_eq!(AtomicInterval::closed(1, i32::MAX).checked_midpoint(), Some(1 + (i32::MAX - 1) / 2));
+        assert_eq!(AtomicInterval::closed(-1, i32::MAX).checked_midpoint(), None);
+        assert_eq!(AtomicInterval::closed(i32::MIN, i32::MAX).checked_midpoint(), None);
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(AtomicInterval::open(1, 2).is_valid());
+        assert!(AtomicInterval::point(2.5).is_valid());
+    }
+
+    #[test]
+    fn test_is_valid_rejects_broken_intervals() {
+        let reversed = AtomicInterval { left: Bound::Included(5), right: Bound::Included(1) };
+        assert!(!reversed.is_valid());
+        let half_open_point = AtomicInterval { left: Bound::Included(1), right: Bound::Excluded(1) };
+        assert!(!half_open_point.is_valid());
+        let open_point = AtomicInterval { left: Bound::Excluded(1), right: Bound::Excluded(1) };
+        assert!(!open_point.is_valid());
+        let nan = AtomicInterval { left: Bound::Included(f64::NAN), right: Bound::Included(1.0) };
+        assert!(!nan.is_valid());
+    }
+
+    #[test]
+    fn test_display_with_width() {
+        let interval = AtomicInterval::open_closed(1, 5);
+        assert_eq!(format!("{}", interval), "(1, 5]");
+        assert_eq!(format!("{:>10}", interval), "    (1, 5]");
+        assert_eq!(format!("{:<10}|", interval), "(1, 5]    |");
+        assert_eq!(format!("{:*^10}", interval), "**(1, 5]**");
+        assert_eq!(format!("{:3}", interval), "(1, 5]");
+    }
+
+    #[test]
+    fn test_display_with_width_and_precision() {
+        let interval = AtomicInterval::closed(1.5, 2.25);
+        assert_eq!(format!("{:.1}", interval), "[1.5, 2.2]");
+        assert_eq!(format!("{:12.2}", interval), "[1.50, 2.25]");
+        assert_eq!(format!("{:>14.2}", interval), "  [1.50, 2.25]");
+        assert_eq!(format!("{:_^15.0}", AtomicInterval::open(1.4, 2.6)), "____(1, 3)_____");
+        assert_eq!(format!("{:4.3}", interval), "[1.500, 2.250]");
+    }
+
+    #[test]
+    fn test_intersect() {
+        let interval = AtomicInterval::closed(1, 5);
+        assert_eq!(interval.intersect(&AtomicInterval::closed(3, 7)), Some(AtomicInterval::closed(3, 5)));
+        assert_eq!(interval.intersect(&AtomicInterval::open(0, 3)), Some(AtomicInterval::closed_open(1, 3)));
+        assert_eq!(interval.intersect(&AtomicInterval::closed(5, 7)), Some(AtomicInterval::point(5)));
+        assert_eq!(interval.intersect(&AtomicInterval::open(5, 7)), None);
+        assert_eq!(interval.intersect(&AtomicInterval::closed(6, 7)), None);
+    }
+
+    #[test]
+    fn test_merge_overlapping_and_adjacent() {
+        let interval = AtomicInterval::closed_open(1, 5);
+        assert_eq!(interval.merge(&AtomicInterval::closed(3, 7)), MergeResult::Merged(AtomicInterval::closed(1, 7)));
+        assert_eq!(interval.merge(&AtomicInterval::closed(5, 7)), MergeResult::Merged(AtomicInterval::closed(1, 7)));
+        assert_eq!(interval.merge(&AtomicInterval::point(0)), MergeResult::Disjoint(AtomicInterval::point(0), interval.clone()));
+    }
+
+    #[test]
+    fn test_merge_disjoint_is_ordered() {
+        let interval1 = AtomicInterval::closed(1, 3);
+        let interval2 = AtomicInterval::open(5, 7);
+        let expected = MergeResult::Disjoint(interval1.clone(), interval2.clone());
+        assert_eq!(interval1.merge(&interval2), expected);
+        assert_eq!(interval2.merge(&interval1), expected);
+        let interval3 = AtomicInterval::open(3, 7);
+        assert_eq!(interval3.merge(&AtomicInterval::closed_open(1, 3)), MergeResult::Disjoint(AtomicInterval::closed_open(1, 3), interval3.clone()));
+    }
+
+    #[test]
+    fn test_into_variants_match_allocating_methods() {
+        let intervals = [
+            AtomicInterval::closed(1, 10),
+            AtomicInterval::open(4, 6),
+            AtomicInterval::closed_open(0, 4),
+            AtomicInterval::point(10),
+            AtomicInterval::open_closed(10, 12),
+        ];
+        for a in &intervals {
+            for b in &intervals {
+                let mut out = vec![AtomicInterval::point(-1)];
+                a.difference_into(b, &mut out);
+                assert_eq!(out[0], AtomicInterval::point(-1));
+                assert_eq!(out[1..], a.difference(b)[..]);
+
+                let mut out = vec![AtomicInterval::point(-1)];
+                a.intersection_into(b, &mut out);
+                assert_eq!(out[0], AtomicInterval::point(-1));
+                assert_eq!(out[1..], a.intersection(b)[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_proper_superset() {
+        let interval = AtomicInterval::closed(1, 5);
+        assert!(interval.is_proper_superset(&AtomicInterval::closed(2, 4)));
+        assert!(interval.is_proper_superset(&AtomicInterval::open_closed(1, 5)));
+        assert!(!interval.is_proper_superset(&AtomicInterval::closed(1, 5)));
+        assert!(!interval.is_proper_superset(&AtomicInterval::closed(0, 5)));
+        assert!(!AtomicInterval::point(1).is_proper_superset(&AtomicInterval::point(1)));
+    }
+
+    #[test]
+    fn test_contains_interval_of_equal_intervals() {
+        let interval = AtomicInterval::open(1, 5);
+        assert!(interval.contains_interval(&AtomicInterval::open(1, 5), false));
+        assert!(!interval.contains_interval(&AtomicInterval::open(1, 5), true));
+        assert!(interval.contains_interval(&AtomicInterval::open(2, 5), true));
+        assert!(!interval.contains_interval(&AtomicInterval::closed(1, 5), false));
+    }
+
+    #[test]
+    fn test_from_center_radius() {
+        assert_eq!(AtomicInterval::from_center_radius(5, 2), AtomicInterval::closed(3, 7));
+        assert_eq!(AtomicInterval::from_center_radius(-1.0, 0.5), AtomicInterval::closed(-1.5, -0.5));
+        assert_eq!(AtomicInterval::from_center_radius(5, 0), AtomicInterval::point(5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_center_radius_negative_radius() {
+        AtomicInterval::from_center_radius(5, -1);
+    }
+
+    #[test]
+    fn test_is_adjacent_discrete() {
+        let interval = AtomicInterval::closed(1, 5);
+        assert!(interval.is_adjacent_discrete(&AtomicInterval::closed(6, 9)));
+        assert!(AtomicInterval::closed(6, 9).is_adjacent_discrete(&interval));
+        assert!(interval.is_adjacent_discrete(&AtomicInterval::open(5, 9)));
+        assert!(AtomicInterval::closed_open(1, 6).is_adjacent_discrete(&AtomicInterval::closed(6, 9)));
+        assert!(interval.is_adjacent_discrete(&AtomicInterval::point(0)));
+        assert!(!interval.is_adjacent_discrete(&AtomicInterval::closed(7, 9)));
+        assert!(!interval.is_adjacent_discrete(&AtomicInterval::closed(5, 9)));
+        assert!(!AtomicInterval::closed(0, u8::MAX).is_adjacent_discrete(&AtomicInterval::closed(0, 1)));
+    }
+
+    #[test]
+    fn test_union_discrete() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(6, 9);
+        assert_eq!(AtomicInterval::union_discrete(&interval1, &interval2), vec![AtomicInterval::closed(1, 9)]);
+        assert_eq!(AtomicInterval::union_discrete(&interval2, &interval1), vec![AtomicInterval::closed(1, 9)]);
+        assert!(AtomicInterval::union(&interval1, &interval2).is_empty());
+        assert_eq!(AtomicInterval::union_discrete(&interval1, &AtomicInterval::open(3, 7)), vec![AtomicInterval::closed_open(1, 7)]);
+        assert!(AtomicInterval::union_discrete(&interval1, &AtomicInterval::closed(7, 9)).is_empty());
+    }
+
+    #[test]
+    fn test_scale() {
+        assert_eq!(AtomicInterval::closed(2, 4).scale(2, 0), AtomicInterval::closed(4, 8));
+        assert_eq!(AtomicInterval::open(2.0, 4.0).scale(0.5, 2.0), AtomicInterval::open(2.0, 3.0));
+        assert_eq!(AtomicInterval::open_closed(-2, 2).scale(3, 0), AtomicInterval::open_closed(-6, 6));
+        assert_eq!(AtomicInterval::point(5).scale(4, 1), AtomicInterval::point(17));
+    }
 
-        // Determine the left boundary of the intersection.
-        let left = if self.left.value() > other.left.value() {
-            self.left.clone()
-        } else {
-            other.left.clone()
-        };
+    #[test]
+    #[should_panic]
+    fn test_scale_non_positive_factor() {
+        AtomicInterval::closed(2, 4).scale(0, 0);
+    }
 
-        // Determine the right boundary of the intersection.
-        let right = if self.right.value() < other.right.value() {
-            self.right.clone()
-        } else {
-            other.right.clone()
-        };
+    #[test]
+    fn test_scale_unsigned_around_inner_origin() {
+        assert_eq!(AtomicInterval::closed(2u32, 4).scale(2, 3), AtomicInterval::closed(1, 5));
+        assert_eq!(AtomicInterval::closed_open(4u8, 10).scale(2, 5), AtomicInterval::closed_open(3, 15));
+    }
 
-        // If they meet at a single point, ensure it's included on both sides.
-        if left.value() == right.value() {
-            return match (left, right) {
-                (Bound::Included(val), Bound::Included(_)) => {
-                    vec![ AtomicInterval { left: Bound::Included(val.clone()), right: Bound::Included(val) } ]
-                }
-                _ => vec![],
-            };
-        }
+    #[test]
+    #[should_panic]
+    fn test_scale_nan_factor() {
+        AtomicInterval::closed(1.0, 2.0).scale(f64::NAN, 0.0);
+    }
 
-        // Otherwise, we have a valid overlapping range.
-        vec![ AtomicInterval { left, right } ]
+    #[test]
+    fn test_clamp_value() {
+        let interval = AtomicInterval::closed(3, 7);
+        assert_eq!(interval.clamp_value(0), 3);
+        assert_eq!(interval.clamp_value(10), 7);
+        assert_eq!(interval.clamp_value(3), 3);
+        assert_eq!(interval.clamp_value(6), 6);
     }
 
-    /// Computes the difference between two intervals.
-    /// The difference between two intervals is the set of intervals that are in the first interval but not in the second interval.
-    /// 
-    /// # Arguments
-    /// * `other` - The other interval to compute the difference with the current interval
-    /// 
-    /// # Returns
-    /// A `Vec` of `AtomicInterval` representing the difference between the two intervals
-    /// 
-    /// # Examples
-    /// ```
-    /// use timekeep_rs::AtomicInterval;
-    /// 
-    /// let interval1 = AtomicInterval::closed(1, 5);
-    /// let interval2 = AtomicInterval::closed(3, 7);
-    /// let difference = interval1.difference(&interval2);
-    /// assert_eq!(difference.len(), 1);
-    /// assert_eq!(difference[0], AtomicInterval::closed_open(1, 3));
-    /// ```
-    /// 
-    pub fn difference(&self, other: &Self) -> Vec<Self> {
-        // If disjoint, difference is just self.
-        if self.is_disjoint(other) {
-            return vec![self.clone()];
-        } else if self.is_subset(other) {
-            return vec![];
-        }
+    #[test]
+    fn test_clamp_value_to_excluded_bounds() {
+        let interval = AtomicInterval::open(3.0, 7.0);
+        assert_eq!(interval.clamp_value(-1.0), 3.0);
+        assert_eq!(interval.clamp_value(7.0), 7.0);
+        assert_eq!(interval.clamp_value(9.5), 7.0);
+        assert_eq!(interval.clamp_value(4.5), 4.5);
+    }
 
-        // If there's no intersection, difference is self.
-        let intersection_vec = self.intersection(other);
-        let intersection = intersection_vec.first().expect("No intersection found!");
+    #[test]
+    fn test_difference_of_interior_point() {
+        let difference = AtomicInterval::closed(1, 5).difference(&AtomicInterval::point(3));
+        assert_eq!(difference, vec![AtomicInterval::closed_open(1, 3), AtomicInterval::open_closed(3, 5)]);
+    }
 
-        let mut result = Vec::new();
+    #[test]
+    fn test_difference_of_left_edge_point() {
+        let difference = AtomicInterval::closed(1, 5).difference(&AtomicInterval::point(1));
+        assert_eq!(difference, vec![AtomicInterval::open_closed(1, 5)]);
+        let difference = AtomicInterval::open(1, 5).difference(&AtomicInterval::point(1));
+        assert_eq!(difference, vec![AtomicInterval::open(1, 5)]);
+    }
 
-        // Left remainder: from self.left up to intersection.left (if any).
-        if intersection.left.value() > self.left.value() {
-            let left_interval = AtomicInterval {
-                left: self.left.clone(),
-                right: match &intersection.left {
-                    Bound::Included(val) => Bound::Excluded(val.clone()),
-                    Bound::Excluded(val) => Bound::Excluded(val.clone()),
-                },
-            };
-            // Only add if valid (left <= right).
-            if left_interval.left.value() < left_interval.right.value() {
-                result.push(left_interval);
-            }
-        }
+    #[test]
+    fn test_difference_of_right_edge_point() {
+        let difference = AtomicInterval::closed(1, 5).difference(&AtomicInterval::point(5));
+        assert_eq!(difference, vec![AtomicInterval::closed_open(1, 5)]);
+        let difference = AtomicInterval::closed_open(1, 5).difference(&AtomicInterval::point(5));
+        assert_eq!(difference, vec![AtomicInterval::closed_open(1, 5)]);
+    }
 
-        // Right remainder: from intersection.right up to self.right (if any).
-        if intersection.right.value() < self.right.value() {
-            let right_interval = AtomicInterval {
-                left: match &intersection.right {
-                    Bound::Included(val) => Bound::Excluded(val.clone()),
-                    Bound::Excluded(val) => Bound::Excluded(val.clone()),
-                },
-                right: self.right.clone(),
-            };
-            // Only add if valid (left <= right).
-            if right_interval.left.value() < right_interval.right.value() {
-                result.push(right_interval);
-            }
+    #[test]
+    fn test_difference_of_point_from_itself() {
+        assert!(AtomicInterval::point(3).difference(&AtomicInterval::point(3)).is_empty());
+        assert_eq!(AtomicInterval::point(3).difference(&AtomicInterval::point(4)), vec![AtomicInterval::point(3)]);
+    }
+
+    #[test]
+    fn test_touches() {
+        let interval = AtomicInterval::closed(1, 5);
+        assert!(interval.touches(&AtomicInterval::open(5, 8)));
+        assert!(interval.touches(&AtomicInterval::closed(5, 8)));
+        assert!(AtomicInterval::open(5, 8).touches(&interval));
+        assert!(AtomicInterval::open(1, 5).touches(&AtomicInterval::open(5, 8)));
+        assert!(!interval.touches(&AtomicInterval::closed(6, 8)));
+        assert!(!interval.touches(&AtomicInterval::closed(2, 4)));
+    }
+
+    #[test]
+    fn test_split_into_three_pieces() {
+        let interval = AtomicInterval::closed(0, 9);
+        let pieces = interval.split_into(3);
+        assert_eq!(pieces, vec![
+            AtomicInterval::closed_open(0, 3),
+            AtomicInterval::closed_open(3, 6),
+            AtomicInterval::closed(6, 9),
+        ]);
+        for pair in pieces.windows(2) {
+            assert!(pair[0].is_disjoint(&pair[1]));
+            assert!(pair[0].is_adjacent(&pair[1]));
         }
+    }
 
-        result
+    #[test]
+    fn test_split_into_edge_cases() {
+        let interval = AtomicInterval::open(0, 9);
+        assert!(interval.split_into(0).is_empty());
+        assert_eq!(interval.split_into(1), vec![interval.clone()]);
+        assert_eq!(interval.split_into(2), vec![AtomicInterval::open(0, 4), AtomicInterval::closed_open(4, 9)]);
+        assert_eq!(AtomicInterval::closed(0, 1).split_into(4), vec![AtomicInterval::closed(0, 1)]);
+        assert_eq!(AtomicInterval::point(3).split_into(2), vec![AtomicInterval::point(3)]);
     }
 
-}
+    #[test]
+    fn test_split_into_more_pieces_than_values() {
+        let interval = AtomicInterval::closed(0u8, 255);
+        let pieces = interval.split_into(1000);
+        assert_eq!(pieces.len(), 255);
+        assert_eq!(pieces[0], AtomicInterval::closed_open(0, 1));
+        assert_eq!(pieces[254], AtomicInterval::closed(254, 255));
+        assert_eq!(AtomicInterval::closed(0, 2).split_into(5), vec![AtomicInterval::closed(0, 2)]);
+        let pieces = AtomicInterval::closed(-50i8, 50).split_into(300);
+        assert_eq!(pieces.len(), 100);
+        assert_eq!(pieces.last(), Some(&AtomicInterval::closed(49, 50)));
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_split_into_floats() {
+        let pieces = AtomicInterval::open_closed(0.0, 3.0).split_into_f64(4);
+        assert_eq!(pieces, vec![
+            AtomicInterval::open(0.0, 0.75),
+            AtomicInterval::closed_open(0.75, 1.5),
+            AtomicInterval::closed_open(1.5, 2.25),
+            AtomicInterval::closed(2.25, 3.0),
+        ]);
+        assert_eq!(AtomicInterval::closed(0.0, 1.0).split_into_f64(1000).len(), 1000);
+        assert_eq!(AtomicInterval::closed(0.0, 1.0).split_into_f64(20_000_000).len(), 20_000_000);
+        assert_eq!(AtomicInterval::point(1.0).split_into_f64(3), vec![AtomicInterval::point(1.0)]);
+        assert!(AtomicInterval::closed(0.0, 1.0).split_into_f64(0).is_empty());
+    }
 
     #[test]
-    fn test_open_interval() {
-        let interval = AtomicInterval::open(1, 5);
-        assert_eq!(interval.left, Bound::Excluded(1));
-        assert_eq!(interval.right, Bound::Excluded(5));
+    fn test_closed_or_point() {
+        assert_eq!(AtomicInterval::closed_or_point(2, 2), Ok(AtomicInterval::point(2)));
+        assert_eq!(AtomicInterval::closed_or_point(1, 4), Ok(AtomicInterval::closed(1, 4)));
+        assert_eq!(AtomicInterval::closed_or_point(4, 1), Err(IntervalError::InvalidBounds));
+        assert_eq!(AtomicInterval::closed_or_point(0.5, 0.5), Ok(AtomicInterval::point(0.5)));
     }
 
     #[test]
-    fn test_closed_interval() {
+    fn test_reflect() {
+        assert_eq!(AtomicInterval::closed(1, 4).reflect(0), AtomicInterval::closed(-4, -1));
+        assert_eq!(AtomicInterval::point(3).reflect(1), AtomicInterval::point(-1));
+        assert_eq!(AtomicInterval::closed(-2.0, 2.0).reflect(0.0), AtomicInterval::closed(-2.0, 2.0));
+    }
+
+    #[test]
+    fn test_reflect_swaps_inclusivity() {
+        assert_eq!(AtomicInterval::closed_open(1, 4).reflect(0), AtomicInterval::open_closed(-4, -1));
+        assert_eq!(AtomicInterval::open_closed(1, 4).reflect(0), AtomicInterval::closed_open(-4, -1));
+        let interval = AtomicInterval::closed_open(2, 7);
+        assert_eq!(interval.reflect(3).reflect(3), interval);
+    }
+
+    #[test]
+    fn test_reflect_unsigned() {
+        assert_eq!(AtomicInterval::closed(4u32, 5).reflect(3), AtomicInterval::closed(1, 2));
+        assert_eq!(AtomicInterval::closed_open(2u8, 6).reflect(4), AtomicInterval::open_closed(2, 6));
+        assert_eq!(AtomicInterval::closed(1u8, 2).reflect(3), AtomicInterval::closed(4, 5));
+    }
+
+    #[test]
+    fn test_step_by_stops_at_infinity() {
+        let interval = AtomicInterval::closed(0.0, f64::INFINITY);
+        assert_eq!(interval.step_by(1e308).collect::<Vec<_>>(), vec![0.0, 1e308]);
+        assert_eq!(interval.step_by(f64::INFINITY).count(), 0);
+    }
+
+    #[test]
+    fn test_with_inclusivity() {
         let interval = AtomicInterval::closed(1, 5);
-        assert_eq!(interval.left, Bound::Included(1));
-        assert_eq!(interval.right, Bound::Included(5));
+        assert_eq!(interval.with_inclusivity(false, false), Some(AtomicInterval::open(1, 5)));
+        assert_eq!(interval.with_inclusivity(true, false), Some(AtomicInterval::closed_open(1, 5)));
+        assert_eq!(interval.with_inclusivity(false, true), Some(AtomicInterval::open_closed(1, 5)));
+        assert_eq!(AtomicInterval::open(1, 5).with_inclusivity(true, true), Some(interval));
     }
 
     #[test]
-    fn test_open_closed_interval() {
-        let interval = AtomicInterval::open_closed(1, 5);
-        assert_eq!(interval.left, Bound::Excluded(1));
-        assert_eq!(interval.right, Bound::Included(5));
+    fn test_with_inclusivity_rejects_empty_point() {
+        let point = AtomicInterval::point(3);
+        assert_eq!(point.with_inclusivity(true, true), Some(point.clone()));
+        assert_eq!(point.with_inclusivity(false, true), None);
+        assert_eq!(point.with_inclusivity(false, false), None);
     }
 
     #[test]
-    fn test_closed_open_interval() {
+    fn test_step_by() {
+        let interval = AtomicInterval::closed(0.0, 1.0);
+        assert_eq!(interval.step_by(0.25).collect::<Vec<_>>(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(interval.step_by(0.4).collect::<Vec<_>>(), vec![0.0, 0.4, 0.8]);
+        assert_eq!(interval.step_by(2.0).collect::<Vec<_>>(), vec![0.0]);
+    }
+
+    #[test]
+    fn test_step_by_respects_inclusivity() {
+        let interval = AtomicInterval::open(0.0, 1.0);
+        assert_eq!(interval.step_by(0.25).collect::<Vec<_>>(), vec![0.25, 0.5, 0.75]);
+        let interval = AtomicInterval::closed_open(0.0, 1.0);
+        assert_eq!(interval.step_by(0.5).collect::<Vec<_>>(), vec![0.0, 0.5]);
+    }
+
+    #[test]
+    fn test_step_by_with_invalid_step() {
+        let interval = AtomicInterval::closed(0.0, 1.0);
+        assert_eq!(interval.step_by(0.0).count(), 0);
+        assert_eq!(interval.step_by(-0.5).count(), 0);
+        assert_eq!(interval.step_by(f64::NAN).count(), 0);
+    }
+
+    #[test]
+    fn test_contains_all() {
         let interval = AtomicInterval::closed_open(1, 5);
-        assert_eq!(interval.left, Bound::Included(1));
-        assert_eq!(interval.right, Bound::Excluded(5));
+        assert!(interval.contains_all(&[1, 2, 4]));
+        assert!(!interval.contains_all(&[1, 2, 5]));
+        assert!(interval.contains_all(&[]));
     }
 
     #[test]
-    fn test_point_interval() {
-        let interval = AtomicInterval::point(1);
-        assert_eq!(interval.left, Bound::Included(1));
-        assert_eq!(interval.right, Bound::Included(1));
+    fn test_contains_any() {
+        let interval = AtomicInterval::closed_open(1, 5);
+        assert!(interval.contains_any(&[0, 5, 4]));
+        assert!(!interval.contains_any(&[0, 5]));
+        assert!(!interval.contains_any(&[]));
     }
 
     #[test]
-    fn test_is_overlapping() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::closed(4, 6);
-        assert!(interval1.is_overlapping(&interval2));
+    fn test_relate_point() {
+        let interval = AtomicInterval::closed(2, 6);
+        assert_eq!(interval.relate_point(&1), Some(PointRelation::Before));
+        assert_eq!(interval.relate_point(&2), Some(PointRelation::OnLeftBound));
+        assert_eq!(interval.relate_point(&4), Some(PointRelation::Inside));
+        assert_eq!(interval.relate_point(&6), Some(PointRelation::OnRightBound));
+        assert_eq!(interval.relate_point(&7), Some(PointRelation::After));
     }
 
     #[test]
-    fn test_is_adjacent() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::open_closed(5, 10);
-        assert!(interval1.is_adjacent(&interval2));
+    fn test_relate_point_on_excluded_bounds() {
+        let interval = AtomicInterval::open(2.0, 6.0);
+        assert_eq!(interval.relate_point(&2.0), Some(PointRelation::OnLeftBound));
+        assert_eq!(interval.relate_point(&6.0), Some(PointRelation::OnRightBound));
+        assert!(!interval.contains(&2.0));
+        assert_eq!(AtomicInterval::point(3).relate_point(&3), Some(PointRelation::OnLeftBound));
     }
 
     #[test]
-    fn test_is_disjoint() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::closed(6, 10);
-        assert!(interval1.is_disjoint(&interval2));
+    fn test_relate_point_incomparable() {
+        let interval = AtomicInterval::closed(1.0, 5.0);
+        assert_eq!(interval.relate_point(&f64::NAN), None);
+        assert!(!interval.contains(&f64::NAN));
+        assert_eq!(interval.relate_point(&3.0), Some(PointRelation::Inside));
     }
 
     #[test]
-    fn test_is_subset() {
-        let interval1 = AtomicInterval::closed(2, 4);
-        let interval2 = AtomicInterval::closed(1, 5);
-        assert!(interval1.is_subset(&interval2));
+    fn test_union_keep_merged() {
+        let interval = AtomicInterval::closed_open(1, 3);
+        assert_eq!(interval.union_keep(&AtomicInterval::closed(3, 5)), vec![AtomicInterval::closed(1, 5)]);
+        assert_eq!(interval.union_keep(&AtomicInterval::point(2)), vec![interval.clone()]);
     }
 
     #[test]
-    fn test_is_superset() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::closed(2, 4);
-        assert!(interval1.is_superset(&interval2));
+    fn test_union_keep_disjoint() {
+        let a = AtomicInterval::closed(1, 3);
+        let b = AtomicInterval::open(3, 5);
+        let c = AtomicInterval::closed(7, 9);
+        assert_eq!(a.union_keep(&c), vec![a.clone(), c.clone()]);
+        assert_eq!(c.union_keep(&a), vec![a.clone(), c.clone()]);
+        // Adjacent intervals are merged.
+        assert_eq!(a.union_keep(&b), vec![AtomicInterval::closed_open(1, 5)]);
     }
 
     #[test]
-    fn test_union_overlapping_intervals() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::closed(4, 7);
-        let merged = AtomicInterval::union(&interval1, &interval2);
-        assert_eq!(merged.len(), 1);
-        assert_eq!(merged.first().unwrap(), &AtomicInterval::closed(1, 7));
+    fn test_to_half_open_is_canonical() {
+        let canonical = Some(AtomicInterval::closed_open(2, 6));
+        assert_eq!(AtomicInterval::closed(2, 5).to_half_open(), canonical);
+        assert_eq!(AtomicInterval::closed_open(2, 6).to_half_open(), canonical);
+        assert_eq!(AtomicInterval::open_closed(1, 5).to_half_open(), canonical);
+        assert_eq!(AtomicInterval::open(1, 6).to_half_open(), canonical);
+        assert_eq!(AtomicInterval::point(4).to_half_open(), Some(AtomicInterval::closed_open(4, 5)));
     }
 
     #[test]
-    fn test_union_adjacent_intervals() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::closed(5, 7);
-        let merged = AtomicInterval::union(&interval1, &interval2);
-        assert_eq!(merged.len(), 1);
-        assert_eq!(merged.first().unwrap(), &AtomicInterval::closed(1, 7));
+    fn test_to_half_open_at_type_limits() {
+        assert_eq!(AtomicInterval::closed(0u8, 255).to_half_open(), None);
+        assert_eq!(AtomicInterval::closed_open(0u8, 255).to_half_open(), Some(AtomicInterval::closed_open(0, 255)));
+        assert_eq!(AtomicInterval::open(i32::MIN, 0).to_half_open(), Some(AtomicInterval::closed_open(i32::MIN + 1, 0)));
+        assert_eq!(AtomicInterval::open_closed(0u8, 255).to_half_open(), None);
     }
 
     #[test]
-    fn test_union_disjoint_intervals() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::closed(6, 7);
-        let merged = AtomicInterval::union(&interval1, &interval2);
-        assert_eq!(merged.len(), 0);
+    fn test_to_half_open_without_discrete_values() {
+        assert_eq!(AtomicInterval::open(4, 5).to_half_open(), None);
+        assert_eq!(AtomicInterval::closed_open(4, 5).to_half_open(), Some(AtomicInterval::closed_open(4, 5)));
+        assert_eq!(AtomicInterval::open(254u8, 255).to_half_open(), None);
+        assert_eq!(AtomicInterval::open_closed('a', 'b').to_half_open(), Some(AtomicInterval::closed_open('b', 'c')));
+        assert_eq!(AtomicInterval::open('a', 'b').to_half_open(), None);
     }
 
     #[test]
-    fn test_intersection_between_two_overlapping_intervals() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::closed(3, 7);
-        let intersection = interval1.intersection(&interval2);
-        assert_eq!(intersection.len(), 1);
-        assert_eq!(intersection.first().unwrap(), &AtomicInterval::closed(3, 5));
+    fn test_saturate_inside() {
+        let interval = AtomicInterval::open_closed(2, 8);
+        assert_eq!(interval.saturate(0, 10), Some(interval.clone()));
+        assert_eq!(interval.saturate(2, 8), Some(interval.clone()));
     }
 
     #[test]
-    fn test_intersection_between_two_disjoint_intervals() {
-        let interval1 = AtomicInterval::closed(1, 3);
-        let interval2 = AtomicInterval::closed(4, 7);
-        let intersection = interval1.intersection(&interval2);
-        assert_eq!(intersection.len(), 0);
+    fn test_saturate_straddling() {
+        assert_eq!(AtomicInterval::closed_open(5, 20).saturate(0, 10), Some(AtomicInterval::closed(5, 10)));
+        assert_eq!(AtomicInterval::open(-5, 5).saturate(0, 10), Some(AtomicInterval::closed_open(0, 5)));
+        assert_eq!(AtomicInterval::closed(10, 20).saturate(0, 10), Some(AtomicInterval::point(10)));
     }
 
     #[test]
-    fn test_intersection_between_two_adjacent_intervals() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::open(5, 7);
-        let intersection = interval1.intersection(&interval2);
-        assert_eq!(intersection.len(), 0);
+    fn test_saturate_outside() {
+        assert_eq!(AtomicInterval::closed(20, 30).saturate(0, 10), None);
+        assert_eq!(AtomicInterval::open(10, 30).saturate(0, 10), None);
+        assert_eq!(AtomicInterval::closed(1, 3).saturate(10, 0), None);
     }
 
     #[test]
-    fn test_difference_between_two_overlapping_intervals() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::closed(3, 7);
-        let difference = interval1.difference(&interval2);
-        assert_eq!(difference.len(), 1);
-        assert_eq!(difference[0], AtomicInterval::closed_open(1, 3));
+    fn test_from_tuple() {
+        assert_eq!(AtomicInterval::from((1, 5)), AtomicInterval::closed(1, 5));
+        let interval: AtomicInterval<f64> = (0.5, 1.5).into();
+        assert_eq!(interval, AtomicInterval::closed(0.5, 1.5));
     }
 
     #[test]
-    fn test_difference_between_subset_and_superset_interval() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::closed(2, 4);
-        let difference = interval1.difference(&interval2);
-        assert_eq!(difference.len(), 2);
-        assert_eq!(difference[0], AtomicInterval::closed_open(1, 2));
-        assert_eq!(difference[1], AtomicInterval::open_closed(4, 5));
+    #[should_panic]
+    fn test_from_reversed_tuple() {
+        let _ = AtomicInterval::from((5, 1));
     }
 
     #[test]
-    fn test_difference_between_two_disjoint_intervals() {
-        let interval1 = AtomicInterval::closed(1, 3);
-        let interval2 = AtomicInterval::closed(4, 7);
-        let difference = interval1.difference(&interval2);
-        assert_eq!(difference.len(), 1);
-        assert_eq!(difference[0], AtomicInterval::closed(1, 3));
+    fn test_extend_to() {
+        let interval = AtomicInterval::closed(1, 5);
+        assert_eq!(interval.extend_to(8), AtomicInterval::closed(1, 8));
+        assert_eq!(interval.extend_to(0), AtomicInterval::closed(0, 5));
+        assert_eq!(interval.extend_to(5), interval);
+        assert_eq!(AtomicInterval::point(3).extend_to(1), AtomicInterval::closed(1, 3));
     }
 
     #[test]
-    fn test_difference_between_two_adjacent_intervals() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::open(5, 7);
-        let difference = interval1.difference(&interval2);
-        assert_eq!(difference.len(), 1);
-        assert_eq!(difference[0], AtomicInterval::closed(1, 5));
+    fn test_extend_to_an_excluded_bound() {
+        let interval = AtomicInterval::open(1, 5);
+        assert_eq!(interval.extend_to(5), AtomicInterval::open_closed(1, 5));
+        assert_eq!(interval.extend_to(1), AtomicInterval::closed_open(1, 5));
+        assert_eq!(interval.extend_to(-2), AtomicInterval::closed_open(-2, 5));
     }
-}
+
+    #[test]
+    fn test_chars() {
+        let interval = AtomicInterval::closed('a', 'e');
+        assert_eq!(interval.chars().collect::<Vec<_>>(), vec!['a', 'b', 'c', 'd', 'e']);
+        assert_eq!(AtomicInterval::open('a', 'e').chars().collect::<String>(), "bcd");
+        assert_eq!(AtomicInterval::point('x').chars().collect::<String>(), "x");
+    }
+
+    #[test]
+    fn test_chars_skip_surrogates() {
+        let interval = AtomicInterval::closed('\u{D7FE}', '\u{E001}');
+        assert_eq!(interval.chars().collect::<Vec<_>>(), vec!['\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}']);
+        assert_eq!(AtomicInterval::closed_open('\u{10FFFE}', char::MAX).chars().count(), 1);
+        assert_eq!(AtomicInterval::closed('\u{10FFFE}', char::MAX).chars().count(), 2);
+    }
+
+    #[test]
+    fn test_generic_methods_on_chars() {
+        let lower = AtomicInterval::closed('a', 'z');
+        assert!(lower.contains(&'q'));
+        assert!(lower.is_adjacent_discrete(&AtomicInterval::closed('{', '~')));
+        assert_eq!(lower.to_half_open(), Some(AtomicInterval::closed_open('a', '{')));
+        assert_eq!(lower.intersect(&AtomicInterval::closed('x', '~')), Some(AtomicInterval::closed('x', 'z')));
+    }
+
+    #[test]
+    fn test_bound_by_side() {
+        let interval = AtomicInterval::closed_open(1, 5);
+        assert_eq!(interval.bound(Side::Left), interval.left());
+        assert_eq!(interval.bound(Side::Right), interval.right());
+        assert_eq!(interval.bound(Side::Right), &Bound::Excluded(5));
+        assert_eq!(interval.bound(Side::Right.opposite()), &Bound::Included(1));
+    }
+
+    #[test]
+    fn test_left_before() {
+        let closed = AtomicInterval::closed(1, 5);
+        let open = AtomicInterval::open(1, 5);
+        assert!(closed.left_before(&open));
+        assert!(!open.left_before(&closed));
+        assert!(!closed.left_before(&closed));
+        assert!(open.left_before(&AtomicInterval::closed(2, 3)));
+        assert!(!AtomicInterval::closed(2, 3).left_before(&open));
+    }
+
+    #[test]
+    fn test_right_before() {
+        let closed = AtomicInterval::closed(1, 5);
+        let open = AtomicInterval::open(1, 5);
+        assert!(open.right_before(&closed));
+        assert!(!closed.right_before(&open));
+        assert!(!open.right_before(&open));
+        assert!(AtomicInterval::closed(1, 4).right_before(&open));
+    }
+
+    #[test]
+    fn test_try_constructors_with_valid_endpoints() {
+        assert_eq!(AtomicInterval::try_open(1, 5), Ok(AtomicInterval::open(1, 5)));
+        assert_eq!(AtomicInterval::try_closed(1, 5), Ok(AtomicInterval::closed(1, 5)));
+        assert_eq!(AtomicInterval::try_open_closed(1, 5), Ok(AtomicInterval::open_closed(1, 5)));
+        assert_eq!(AtomicInterval::try_closed_open(1, 5), Ok(AtomicInterval::closed_open(1, 5)));
+        assert_eq!(*AtomicInterval::try_open_closed(1, 5).unwrap().left(), Bound::Excluded(1));
+        assert_eq!(*AtomicInterval::try_closed_open(1, 5).unwrap().right(), Bound::Excluded(5));
+    }
+
+    #[test]
+    fn test_try_constructors_with_invalid_endpoints() {
+        for (left, right) in [(5, 5), (5, 1)] {
+            assert_eq!(AtomicInterval::try_open(left, right), Err(IntervalError::InvalidBounds));
+            assert_eq!(AtomicInterval::try_closed(left, right), Err(IntervalError::InvalidBounds));
+            assert_eq!(AtomicInterval::try_open_closed(left, right), Err(IntervalError::InvalidBounds));
+            assert_eq!(AtomicInterval::try_closed_open(left, right), Err(IntervalError::InvalidBounds));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The following condition must be valid: `left < right`")]
+    fn test_closed_open_delegates_panic() {
+        AtomicInterval::closed_open(3, 1);
+    }
+
+    #[test]
+    fn test_clamp_to_set() {
+        let allowed = IntervalSet::from(vec![AtomicInterval::closed(2, 4), AtomicInterval::closed(7, 9)]);
+        let interval = AtomicInterval::closed(1, 10);
+        assert_eq!(interval.clamp_to_set(&allowed), allowed);
+        assert_eq!(interval.clamp_to_set(&allowed), IntervalSet::from(interval.clone()).intersection(&allowed));
+    }
+
+    #[test]
+    fn test_clamp_to_set_partially_and_outside() {
+        let allowed = IntervalSet::from(vec![AtomicInterval::closed(2, 4), AtomicInterval::closed(7, 9)]);
+        assert_eq!(AtomicInterval::open(3, 8).clamp_to_set(&allowed).intervals, vec![
+            AtomicInterval::open_closed(3, 4),
+            AtomicInterval::closed_open(7, 8),
+        ]);
+        assert!(AtomicInterval::open(4, 7).clamp_to_set(&allowed).is_empty());
+        assert!(AtomicInterval::closed(1, 10).clamp_to_set(&IntervalSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_intersection_with_set() {
+        let set = IntervalSet::from(vec![AtomicInterval::closed(2, 5), AtomicInterval::closed(10, 12)]);
+        let interval = AtomicInterval::closed(0, 20);
+        assert_eq!(interval.intersection_with_set(&set), set);
+        assert_eq!(interval.intersection_with_set(&set), set.intersect_atom(&interval));
+        assert_eq!(
+            AtomicInterval::open(5, 10).intersection_with_set(&set),
+            IntervalSet::new(),
+        );
+    }
+
+    #[test]
+    fn test_trim_left() {
+        let interval = AtomicInterval::closed(1, 10);
+        assert_eq!(interval.trim_left(&4), Some(AtomicInterval::closed(4, 10)));
+        assert_eq!(interval.trim_left(&10), Some(AtomicInterval::point(10)));
+        assert_eq!(interval.trim_left(&1), Some(interval.clone()));
+        assert_eq!(interval.trim_left(&-3), Some(interval.clone()));
+        assert_eq!(interval.trim_left(&11), None);
+        assert_eq!(AtomicInterval::open(1, 10).trim_left(&1), Some(AtomicInterval::open(1, 10)));
+        assert_eq!(AtomicInterval::closed_open(1, 10).trim_left(&10), None);
+    }
+
+    #[test]
+    fn test_trim_right() {
+        let interval = AtomicInterval::closed(1, 10);
+        assert_eq!(interval.trim_right(&4), Some(AtomicInterval::closed(1, 4)));
+        assert_eq!(interval.trim_right(&1), Some(AtomicInterval::point(1)));
+        assert_eq!(interval.trim_right(&15), Some(interval.clone()));
+        assert_eq!(interval.trim_right(&0), None);
+        assert_eq!(AtomicInterval::open_closed(1, 10).trim_right(&1), None);
+        assert_eq!(AtomicInterval::open(1, 10).trim_right(&10), Some(AtomicInterval::open(1, 10)));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("[1, 5]".parse(), Ok(AtomicInterval::closed(1, 5)));
+        assert_eq!("(1, 5)".parse(), Ok(AtomicInterval::open(1, 5)));
+        assert_eq!(" (-1,5] ".parse(), Ok(AtomicInterval::open_closed(-1, 5)));
+        assert_eq!("[3, 3]".parse(), Ok(AtomicInterval::point(3)));
+        assert_eq!("[0.5, 2.25)".parse(), Ok(AtomicInterval::closed_open(0.5, 2.25)));
+    }
+
+    #[test]
+    fn test_from_str_round_trips_display() {
+        for interval in [AtomicInterval::closed(1, 5), AtomicInterval::open(-3, 0), AtomicInterval::point(7)] {
+            assert_eq!(interval.to_string().parse(), Ok(interval));
+        }
+    }
+
+    #[test]
+    fn test_from_str_with_invalid_text() {
+        for text in ["", "[", "[]", "[1, 5", "1, 5]", "{1, 5}", "[1 5]", "[a, 5]", "[1, 2, 3]", "[é, 5]"] {
+            assert_eq!(text.parse::<AtomicInterval<i32>>(), Err(IntervalError::InvalidFormat), "{}", text);
+        }
+        assert_eq!("(3, 3]".parse::<AtomicInterval<i32>>(), Err(IntervalError::InvalidBounds));
+    }
+}
\ No newline at end of file
@@ -10,9 +10,17 @@
 //! ```
 //!
 
-#[derive(Debug, PartialOrd, PartialEq, Clone, Copy)]
+use alloc::format;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt;
+
+#[derive(Debug, PartialOrd, PartialEq, Eq, Hash, Clone, Copy)]
 /// Represents a boundary of an interval.
 /// Can be either inclusive (closed) or exclusive (open).
+///
+/// Two bounds are equal only if both their kind and their value are equal, so `Included(x)` and
+/// `Excluded(x)` are distinct, and hashing is consistent with this: the kind is hashed along with the value.
 pub enum Bound<T> {
     /// Represents an inclusive boundary, meaning the value itself is included in the interval.
     Included(T),
@@ -20,6 +28,34 @@ pub enum Bound<T> {
     Excluded(T),
 }
 
+/// Represents a side of an interval, designating the bound delimiting it on that side.
+///
+/// # Examples
+/// ```
+/// use timekeep_rs::{AtomicInterval, Bound, Side};
+///
+/// let interval = AtomicInterval::closed_open(1, 5);
+/// assert_eq!(interval.bound(Side::Left), &Bound::Included(1));
+/// assert_eq!(interval.bound(Side::Left.opposite()), &Bound::Excluded(5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The side of the smaller values, where the interval starts.
+    Left,
+    /// The side of the greater values, where the interval ends.
+    Right,
+}
+
+impl Side {
+    /// Returns the other side.
+    pub fn opposite(&self) -> Side {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
 /// Methods for `Bound`.
 impl<T> Bound<T> {
     /// Returns a reference to the value contained within the `Bound`.
@@ -41,11 +77,143 @@ impl<T> Bound<T> {
             Bound::Excluded(value) => value,
         }
     }
+
+    /// Consumes the `Bound` and returns the value contained within it, without cloning it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::Bound;
+    ///
+    /// let exclusive_bound = Bound::Excluded(String::from("end"));
+    /// assert_eq!(exclusive_bound.into_inner(), "end");
+    /// ```
+    pub fn into_inner(self) -> T {
+        match self {
+            Bound::Included(value) => value,
+            Bound::Excluded(value) => value,
+        }
+    }
+
+    /// Maps the value contained within the `Bound`, preserving its inclusivity.
+    pub(crate) fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Bound<U> {
+        match self {
+            Bound::Included(value) => Bound::Included(f(value)),
+            Bound::Excluded(value) => Bound::Excluded(f(value)),
+        }
+    }
+}
+
+/// Methods for comparing `Bound`s according to the side of the interval they delimit.
+impl<T: PartialOrd> Bound<T> {
+    /// Compares two bounds acting as left endpoints of intervals.
+    ///
+    /// A bound is less than another if the interval it starts begins earlier. At equal values,
+    /// an included bound starts before an excluded one. Incomparable values are treated as equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use timekeep_rs::Bound;
+    ///
+    /// assert_eq!(Bound::Included(5).cmp_as_left(&Bound::Excluded(5)), Ordering::Less);
+    /// assert_eq!(Bound::Excluded(4).cmp_as_left(&Bound::Included(5)), Ordering::Less);
+    /// ```
+    pub fn cmp_as_left(&self, other: &Bound<T>) -> Ordering {
+        match self.value().partial_cmp(other.value()).unwrap_or(Ordering::Equal) {
+            Ordering::Equal => match (self, other) {
+                (Bound::Included(_), Bound::Excluded(_)) => Ordering::Less,
+                (Bound::Excluded(_), Bound::Included(_)) => Ordering::Greater,
+                (_, _) => Ordering::Equal,
+            },
+            ordering => ordering,
+        }
+    }
+
+    /// Compares two bounds acting as right endpoints of intervals.
+    ///
+    /// A bound is less than another if the interval it ends finishes earlier. At equal values,
+    /// an excluded bound ends before an included one. Incomparable values are treated as equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use timekeep_rs::Bound;
+    ///
+    /// assert_eq!(Bound::Excluded(5).cmp_as_right(&Bound::Included(5)), Ordering::Less);
+    /// assert_eq!(Bound::Included(5).cmp_as_right(&Bound::Excluded(6)), Ordering::Less);
+    /// ```
+    pub fn cmp_as_right(&self, other: &Bound<T>) -> Ordering {
+        match self.value().partial_cmp(other.value()).unwrap_or(Ordering::Equal) {
+            Ordering::Equal => match (self, other) {
+                (Bound::Excluded(_), Bound::Included(_)) => Ordering::Less,
+                (Bound::Included(_), Bound::Excluded(_)) => Ordering::Greater,
+                (_, _) => Ordering::Equal,
+            },
+            ordering => ordering,
+        }
+    }
+}
+
+/// Implementation of the `Display` trait for `Bound`.
+impl<T: fmt::Display> fmt::Display for Bound<T> {
+    /// Formats the bound as its value alone, honoring any width or precision requested.
+    /// The bracket of a bound depends on the side of the interval it delimits,
+    /// so use [`Bound::fmt_as_left`] or [`Bound::fmt_as_right`] to render it.
+    ///
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::Bound;
+    ///
+    /// assert_eq!(Bound::Excluded(5).to_string(), "5");
+    /// assert_eq!(format!("{:.1}", Bound::Included(2.0)), "2.0");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.value(), f)
+    }
+}
+
+/// Methods for rendering `Bound`s according to the side of the interval they delimit.
+impl<T: fmt::Display> Bound<T> {
+    /// Renders the bound as the left endpoint of an interval, with its bracket before the value.
+    ///
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::Bound;
+    ///
+    /// assert_eq!(Bound::Included(5).fmt_as_left(), "[5");
+    /// assert_eq!(Bound::Excluded(5).fmt_as_left(), "(5");
+    /// ```
+    pub fn fmt_as_left(&self) -> String {
+        match self {
+            Bound::Included(value) => format!("[{}", value),
+            Bound::Excluded(value) => format!("({}", value),
+        }
+    }
+
+    /// Renders the bound as the right endpoint of an interval, with its bracket after the value.
+    ///
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::Bound;
+    ///
+    /// assert_eq!(Bound::Included(5).fmt_as_right(), "5]");
+    /// assert_eq!(Bound::Excluded(5).fmt_as_right(), "5)");
+    /// ```
+    pub fn fmt_as_right(&self) -> String {
+        match self {
+            Bound::Included(value) => format!("{}]", value),
+            Bound::Excluded(value) => format!("{})", value),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Bound;
+    use std::cmp::Ordering;
 
     #[test]
     fn test_included() {
@@ -110,4 +278,70 @@ mod tests {
         let bound6 = Bound::Excluded(25);
         assert!(bound5 < bound6);
     }
+
+    #[test]
+    fn test_cmp_as_left_ties() {
+        assert_eq!(Bound::Included(5).cmp_as_left(&Bound::Excluded(5)), Ordering::Less);
+        assert_eq!(Bound::Excluded(5).cmp_as_left(&Bound::Included(5)), Ordering::Greater);
+        assert_eq!(Bound::Included(5).cmp_as_left(&Bound::Included(5)), Ordering::Equal);
+        assert_eq!(Bound::Excluded(5).cmp_as_left(&Bound::Excluded(5)), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_cmp_as_left_values() {
+        assert_eq!(Bound::Excluded(4).cmp_as_left(&Bound::Included(5)), Ordering::Less);
+        assert_eq!(Bound::Included(6).cmp_as_left(&Bound::Excluded(5)), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_cmp_as_right_ties() {
+        assert_eq!(Bound::Excluded(5).cmp_as_right(&Bound::Included(5)), Ordering::Less);
+        assert_eq!(Bound::Included(5).cmp_as_right(&Bound::Excluded(5)), Ordering::Greater);
+        assert_eq!(Bound::Included(5).cmp_as_right(&Bound::Included(5)), Ordering::Equal);
+        assert_eq!(Bound::Excluded(5).cmp_as_right(&Bound::Excluded(5)), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_cmp_as_right_values() {
+        assert_eq!(Bound::Included(4).cmp_as_right(&Bound::Excluded(5)), Ordering::Less);
+        assert_eq!(Bound::Excluded(6).cmp_as_right(&Bound::Included(5)), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_cmp_with_incomparable_values() {
+        assert_eq!(Bound::Included(f64::NAN).cmp_as_left(&Bound::Included(1.0)), Ordering::Equal);
+        assert_eq!(Bound::Included(f64::NAN).cmp_as_right(&Bound::Excluded(1.0)), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_into_inner() {
+        assert_eq!(Bound::Excluded(9).into_inner(), 9);
+        assert_eq!(Bound::Included(-1.5).into_inner(), -1.5);
+    }
+
+    #[test]
+    fn test_hash_distinguishes_kinds() {
+        use std::collections::HashSet;
+
+        let mut bounds = HashSet::new();
+        assert!(bounds.insert(Bound::Included(5)));
+        assert!(bounds.insert(Bound::Excluded(5)));
+        assert!(!bounds.insert(Bound::Included(5)));
+        assert_eq!(bounds.len(), 2);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Bound::Included(5).to_string(), "5");
+        assert_eq!(Bound::Excluded(5).to_string(), "5");
+        assert_eq!(format!("{:>3}", Bound::Excluded(5)), "  5");
+    }
+
+    #[test]
+    fn test_fmt_as_left_and_right() {
+        assert_eq!(Bound::Excluded(5).fmt_as_left(), "(5");
+        assert_eq!(Bound::Included(5).fmt_as_left(), "[5");
+        assert_eq!(Bound::Excluded(5).fmt_as_right(), "5)");
+        assert_eq!(Bound::Included(-1.5).fmt_as_right(), "-1.5]");
+    }
 }
\ No newline at end of file
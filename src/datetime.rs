@@ -0,0 +1,130 @@
+//! A module containing conveniences for intervals over [`chrono`] date-times.
+//! It is only available with the `chrono` feature enabled.
+//! 
+//! The generic [`AtomicInterval::length`] already returns a [`chrono::Duration`] for date-time bounds.
+//! 
+//! # Examples
+//! ```
+//! use chrono::{Duration, TimeZone, Utc};
+//! use timekeep_rs::AtomicInterval;
+//! 
+//! let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+//! let meeting = AtomicInterval::from_start_duration(start, Duration::hours(2));
+//! assert_eq!(meeting.length(), Duration::hours(2));
+//! ```
+//!
+use chrono::{DateTime, Duration, TimeZone};
+
+use crate::{AsF64, AtomicInterval};
+
+/// A collection of constructors for creating intervals over date-times.
+impl<Tz: TimeZone> AtomicInterval<DateTime<Tz>> {
+    /// Creates a left-closed, right-open interval [start, start + duration).
+    ///
+    /// # Arguments
+    /// * `start` - The start of the interval
+    /// * `duration` - The length of the interval, which must be positive
+    ///
+    /// # Returns
+    /// A new `AtomicInterval` starting at `start` and lasting `duration`
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 1, 1, 9, 30, 0).unwrap();
+    /// let interval = AtomicInterval::from_start_duration(start, Duration::minutes(30));
+    /// assert_eq!(interval, AtomicInterval::closed_open(start, end));
+    /// ```
+    pub fn from_start_duration(start: DateTime<Tz>, duration: Duration) -> Self {
+        let end = start.clone() + duration;
+        AtomicInterval::closed_open(start, end)
+    }
+
+    /// Creates a left-closed, right-open interval [end - duration, end).
+    ///
+    /// # Arguments
+    /// * `duration` - The length of the interval, which must be positive
+    /// * `end` - The end of the interval
+    ///
+    /// # Returns
+    /// A new `AtomicInterval` lasting `duration` and ending at `end`
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use timekeep_rs::AtomicInterval;
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap();
+    /// let interval = AtomicInterval::from_duration_end(Duration::hours(1), end);
+    /// assert_eq!(interval, AtomicInterval::closed_open(start, end));
+    /// ```
+    pub fn from_duration_end(duration: Duration, end: DateTime<Tz>) -> Self {
+        let start = end.clone() - duration;
+        AtomicInterval::closed_open(start, end)
+    }
+}
+
+/// Durations convert to a number of nanoseconds, or of whole milliseconds times a million beyond
+/// the roughly 292 years that fit in nanoseconds.
+impl AsF64 for Duration {
+    fn as_f64(&self) -> f64 {
+        match self.num_nanoseconds() {
+            Some(nanoseconds) => nanoseconds as f64,
+            None => self.num_milliseconds() as f64 * 1e6,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_length_of_two_hour_interval() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 10, 8, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 3, 10, 10, 0, 0).unwrap();
+        let interval = AtomicInterval::closed(start, end);
+        assert_eq!(interval.length(), Duration::hours(2));
+    }
+
+    #[test]
+    fn test_from_start_duration() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 10, 8, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 3, 10, 10, 0, 0).unwrap();
+        let interval = AtomicInterval::from_start_duration(start, Duration::hours(2));
+        assert_eq!(interval, AtomicInterval::closed_open(start, end));
+        assert_eq!(interval.length(), Duration::hours(2));
+    }
+
+    #[test]
+    fn test_from_duration_end() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 10, 23, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 3, 11, 1, 0, 0).unwrap();
+        let interval = AtomicInterval::from_duration_end(Duration::hours(2), end);
+        assert_eq!(interval, AtomicInterval::closed_open(start, end));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_start_duration_with_negative_duration() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 10, 8, 0, 0).unwrap();
+        AtomicInterval::from_start_duration(start, Duration::hours(-2));
+    }
+
+    #[test]
+    fn test_jaccard_of_date_time_sets() {
+        use crate::IntervalSet;
+
+        let start = Utc.with_ymd_and_hms(2024, 3, 10, 8, 0, 0).unwrap();
+        let a = IntervalSet::from(AtomicInterval::from_start_duration(start, Duration::hours(2)));
+        let b = IntervalSet::from(AtomicInterval::from_start_duration(start + Duration::hours(1), Duration::hours(2)));
+        assert_eq!(a.jaccard(&b), 1.0 / 3.0);
+        assert_eq!(a.coverage_ratio(&b), 0.5);
+        assert_eq!(Duration::seconds(2).as_f64(), 2e9);
+    }
+}
\ No newline at end of file
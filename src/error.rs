@@ -0,0 +1,45 @@
+//! A module containing the `IntervalError` enum, returned by the fallible operations of the library.
+//! 
+//! # Examples
+//! ```
+//! use timekeep_rs::{AtomicInterval, IntervalError, IntervalMap};
+//! 
+//! let mut map = IntervalMap::new();
+//! map.insert(AtomicInterval::closed(1, 5), "a").unwrap();
+//! assert_eq!(map.insert(AtomicInterval::closed(3, 7), "b"), Err(IntervalError::Overlapping));
+//! ```
+//!
+use core::fmt;
+
+/// Represents the errors produced by the fallible operations on intervals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalError {
+    /// The bounds do not enclose any point, as the left bound lies after the right bound.
+    InvalidBounds,
+    /// The interval overlaps with an interval that is already present.
+    Overlapping,
+    /// The intervals are not in ascending order.
+    Unsorted,
+    /// A bound cannot be compared with the other bounds, such as a `NaN` float.
+    IncomparableBound,
+    /// An interval has an excluded bound, which the requested representation cannot express.
+    ExcludedBound,
+    /// The text does not represent an interval, such as `[1, 5)`.
+    InvalidFormat,
+}
+
+impl fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntervalError::InvalidBounds => write!(f, "the left bound must not lie after the right bound"),
+            IntervalError::Overlapping => write!(f, "the interval overlaps with an existing interval"),
+            IntervalError::Unsorted => write!(f, "the intervals are not in ascending order"),
+            IntervalError::IncomparableBound => write!(f, "a bound cannot be compared with the other bounds"),
+            IntervalError::ExcludedBound => write!(f, "an interval has an excluded bound"),
+            IntervalError::InvalidFormat => write!(f, "the text does not represent an interval"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IntervalError {}
@@ -0,0 +1,96 @@
+//! A module containing the `IntervalLike` trait, abstracting over atomic intervals and interval sets.
+//!
+//! # Examples
+//! ```
+//! use timekeep_rs::{AtomicInterval, IntervalLike, IntervalSet};
+//!
+//! fn count_atoms<T, I: IntervalLike<T>>(interval: &I) -> usize {
+//!     interval.iter_atoms().count()
+//! }
+//!
+//! assert_eq!(count_atoms(&AtomicInterval::closed(1, 5)), 1);
+//! assert_eq!(count_atoms(&IntervalSet::<i32>::new()), 0);
+//! ```
+//!
+use crate::{AtomicInterval, IntervalSet};
+
+/// A trait for types denoting a set of points as a sequence of atomic intervals,
+/// so that generic algorithms can work on both an `AtomicInterval` and an `IntervalSet`.
+pub trait IntervalLike<T> {
+    /// Checks if a value lies within the interval.
+    fn contains(&self, value: &T) -> bool;
+
+    /// Checks if the interval holds no point.
+    fn is_empty(&self) -> bool;
+
+    /// Returns an iterator over the atomic intervals making up the interval, in ascending order.
+    fn iter_atoms<'a>(&'a self) -> impl Iterator<Item = &'a AtomicInterval<T>>
+    where
+        T: 'a;
+}
+
+impl<T: PartialOrd> IntervalLike<T> for AtomicInterval<T> {
+    fn contains(&self, value: &T) -> bool {
+        AtomicInterval::contains(self, value)
+    }
+
+    /// An atomic interval always holds at least one point, so it is never empty.
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    fn iter_atoms<'a>(&'a self) -> impl Iterator<Item = &'a AtomicInterval<T>>
+    where
+        T: 'a,
+    {
+        core::iter::once(self)
+    }
+}
+
+impl<T: PartialOrd + Clone> IntervalLike<T> for IntervalSet<T> {
+    fn contains(&self, value: &T) -> bool {
+        IntervalSet::contains(self, value)
+    }
+
+    fn is_empty(&self) -> bool {
+        IntervalSet::is_empty(self)
+    }
+
+    fn iter_atoms<'a>(&'a self) -> impl Iterator<Item = &'a AtomicInterval<T>>
+    where
+        T: 'a,
+    {
+        self.intervals.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks the consistency of the trait methods on any implementation.
+    fn check<I: IntervalLike<i32>>(interval: &I, inside: i32, outside: i32, atoms: usize) {
+        assert!(interval.contains(&inside));
+        assert!(!interval.contains(&outside));
+        assert!(!interval.is_empty());
+        assert_eq!(interval.iter_atoms().count(), atoms);
+        assert!(interval.iter_atoms().any(|atom| atom.contains(&inside)));
+    }
+
+    #[test]
+    fn test_interval_like_implementations() {
+        check(&AtomicInterval::closed(1, 5), 3, 6, 1);
+
+        let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::open(5, 8)));
+        check(&set, 6, 4, 2);
+    }
+
+    #[test]
+    fn test_interval_like_empty_set() {
+        let set = IntervalSet::<i32>::new();
+        assert!(IntervalLike::is_empty(&set));
+        assert!(!IntervalLike::contains(&set, &0));
+        assert_eq!(set.iter_atoms().count(), 0);
+    }
+}
\ No newline at end of file
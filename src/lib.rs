@@ -7,9 +7,17 @@
 //!
 //! ## Modules
 //!
-//! - [`atomic`]: Defines the [`AtomicInterval`] struct and its associated methods.
-//! - [`bound`]: Defines the [`Bound`] enum, representing the boundaries of an interval.
-//! - [`set`]: Defines the [`Interval`] struct, representing a set of intervals, and its associated methods.
+//! - [`atomic`]: Defines the [`AtomicInterval`] struct and its associated methods, and the [`MergeResult`] enum.
+//! - [`bound`]: Defines the [`Bound`] enum, representing the boundaries of an interval, and the [`Side`] enum.
+//! - [`set`]: Defines the [`Interval`] struct, representing a set of intervals, and its associated methods, the [`IntervalSetBuilder`] struct and the [`Segment`] enum.
+//! - [`map`]: Defines the [`IntervalMap`] struct, associating values to non-overlapping intervals.
+//! - [`error`]: Defines the [`IntervalError`] enum, returned by fallible operations.
+//! - [`relation`]: Defines the [`AllenRelation`] enum, describing how two intervals relate, and the [`PointRelation`] enum, describing where a value lies relative to an interval.
+//! - [`interval_like`]: Defines the [`IntervalLike`] trait, abstracting over atomic intervals and interval sets.
+//! - [`prelude`]: Re-exports the types and traits needed by most users, for `use timekeep_rs::prelude::*`.
+//! - [`num`]: Defines the [`CheckedOps`], [`Discrete`] and [`AsF64`] traits, used by overflow-aware, discrete and ratio numeric methods.
+//! - `datetime`: Adds constructors for intervals over `chrono` date-times (requires the `chrono` feature).
+//! - `serde_string`: Serializes intervals as their textual representation, such as `"[1, 5)"` (requires the `serde-string` feature).
 //!
 //! ## Usage
 //!
@@ -30,11 +38,32 @@
 //!
 //! println!("Interval: {}", interval.to_string());
 //! ```
+//!
+//! The library is `no_std` compatible when its default `std` feature is disabled, requiring only `alloc`.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 pub mod set;
 pub mod atomic;
 pub mod bound;
+pub mod map;
+pub mod error;
+pub mod relation;
+pub mod num;
+pub mod interval_like;
+pub mod prelude;
+#[cfg(feature = "chrono")]
+pub mod datetime;
+#[cfg(feature = "serde-string")]
+pub mod serde_string;
 
-pub use atomic::AtomicInterval;
-pub use bound::Bound;
-pub use set::IntervalSet;
\ No newline at end of file
+pub use atomic::{AtomicInterval, MergeResult};
+pub use bound::{Bound, Side};
+pub use set::{IntervalSet, IntervalSetBuilder, Segment};
+pub use map::IntervalMap;
+pub use error::IntervalError;
+pub use relation::{AllenRelation, PointRelation};
+pub use num::{AsF64, CheckedOps, Discrete};
+pub use interval_like::IntervalLike;
\ No newline at end of file
@@ -0,0 +1,215 @@
+//! A module containing the `IntervalMap` struct and its implementations.
+//! An interval map associates a value to each of a collection of non-overlapping atomic intervals,
+//! and can be queried by point.
+//! 
+//! # Examples
+//! ```
+//! use timekeep_rs::{AtomicInterval, IntervalMap};
+//! 
+//! let mut prices = IntervalMap::new();
+//! prices.insert(AtomicInterval::closed_open(0, 10), 100).unwrap();
+//! prices.insert(AtomicInterval::closed_open(10, 20), 120).unwrap();
+//! 
+//! assert_eq!(prices.get(&12), Some(&120));
+//! assert_eq!(prices.get(&25), None);
+//! ```
+//!
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{AtomicInterval, IntervalError};
+
+/// A struct mapping non-overlapping atomic intervals to values.
+/// 
+/// # Fields
+/// * `entries` - The intervals and their values, kept sorted by left bound
+/// 
+/// # Examples
+/// ```
+/// use timekeep_rs::{AtomicInterval, IntervalMap};
+///
+/// let mut map = IntervalMap::new();
+/// map.insert(AtomicInterval::closed(1, 5), "low").unwrap();
+/// assert_eq!(map.get(&3), Some(&"low"));
+/// ```
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalMap<K, V> {
+    entries: Vec<(AtomicInterval<K>, V)>,
+}
+
+impl<K, V> Default for IntervalMap<K, V> {
+    /// Returns an empty `IntervalMap`, same as [`IntervalMap::new`].
+    fn default() -> Self {
+        IntervalMap::new()
+    }
+}
+
+impl<K, V> IntervalMap<K, V> {
+    /// Returns an empty `IntervalMap`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::IntervalMap;
+    ///
+    /// let map = IntervalMap::<i32, &str>::new();
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        IntervalMap { entries: vec![] }
+    }
+
+    /// Returns the number of intervals in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map holds no intervals.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the intervals and their values, sorted by left bound.
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, IntervalMap};
+    ///
+    /// let mut map = IntervalMap::new();
+    /// map.insert(AtomicInterval::closed(5, 7), "b").unwrap();
+    /// map.insert(AtomicInterval::closed(1, 3), "a").unwrap();
+    ///
+    /// let values: Vec<_> = map.iter().map(|(_, value)| *value).collect();
+    /// assert_eq!(values, vec!["a", "b"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&AtomicInterval<K>, &V)> {
+        self.entries.iter().map(|(interval, value)| (interval, value))
+    }
+}
+
+impl<K: PartialOrd, V> IntervalMap<K, V> {
+    /// Inserts an interval with its associated value.
+    /// 
+    /// # Arguments
+    /// * `interval` - The interval to use as key
+    /// * `value` - The value associated to the interval
+    /// 
+    /// # Returns
+    /// `Ok(())` if the interval was inserted, or `Err(IntervalError::Overlapping)` if it overlaps
+    /// with an interval already in the map, in which case the map is left unchanged
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, IntervalError, IntervalMap};
+    ///
+    /// let mut map = IntervalMap::new();
+    /// assert!(map.insert(AtomicInterval::closed(1, 5), 'a').is_ok());
+    /// assert_eq!(map.insert(AtomicInterval::closed(5, 9), 'b'), Err(IntervalError::Overlapping));
+    /// ```
+    pub fn insert(&mut self, interval: AtomicInterval<K>, value: V) -> Result<(), IntervalError> {
+        // Find the first entry starting after the new interval.
+        let index = self.entries.partition_point(
+            |(existing, _)| existing.left().cmp_as_left(interval.left()) == Ordering::Less
+        );
+
+        // Since the entries are disjoint and sorted, only the neighbours can overlap.
+        let overlaps_previous = index > 0 && self.entries[index - 1].0.is_overlapping(&interval);
+        let overlaps_next = index < self.entries.len() && self.entries[index].0.is_overlapping(&interval);
+        if overlaps_previous || overlaps_next {
+            return Err(IntervalError::Overlapping);
+        }
+
+        self.entries.insert(index, (interval, value));
+        Ok(())
+    }
+
+    /// Returns the value of the interval containing a point.
+    /// 
+    /// # Arguments
+    /// * `point` - The point to look up
+    /// 
+    /// # Returns
+    /// `Some(&value)` for the interval containing `point`, `None` if no interval contains it
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, IntervalMap};
+    ///
+    /// let mut map = IntervalMap::new();
+    /// map.insert(AtomicInterval::closed_open(1, 5), 'a').unwrap();
+    /// assert_eq!(map.get(&1), Some(&'a'));
+    /// assert_eq!(map.get(&5), None);
+    /// ```
+    pub fn get(&self, point: &K) -> Option<&V> {
+        // Find the first entry whose left bound value lies after the point.
+        let index = self.entries.partition_point(|(interval, _)| interval.left().value() <= point);
+
+        // The containing interval is either the last entry starting at or before the point,
+        // or the one before it when the last entry is left-open at the point itself.
+        self.entries[..index]
+            .iter()
+            .rev()
+            .take(2)
+            .find(|(interval, _)| interval.contains(point))
+            .map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_keeps_entries_sorted() {
+        let mut map = IntervalMap::new();
+        map.insert(AtomicInterval::closed(10, 15), 'c').unwrap();
+        map.insert(AtomicInterval::closed(1, 3), 'a').unwrap();
+        map.insert(AtomicInterval::open(3, 10), 'b').unwrap();
+        let values: Vec<char> = map.iter().map(|(_, value)| *value).collect();
+        assert_eq!(values, vec!['a', 'b', 'c']);
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn test_insert_overlapping_interval() {
+        let mut map = IntervalMap::new();
+        map.insert(AtomicInterval::closed(1, 5), 'a').unwrap();
+        map.insert(AtomicInterval::closed(10, 15), 'b').unwrap();
+        assert_eq!(map.insert(AtomicInterval::closed(4, 11), 'c'), Err(IntervalError::Overlapping));
+        assert_eq!(map.insert(AtomicInterval::point(5), 'c'), Err(IntervalError::Overlapping));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn test_get_hits_correct_payload() {
+        let mut map = IntervalMap::new();
+        map.insert(AtomicInterval::closed_open(0, 10), 100).unwrap();
+        map.insert(AtomicInterval::closed_open(10, 20), 120).unwrap();
+        map.insert(AtomicInterval::closed(30, 40), 150).unwrap();
+        assert_eq!(map.get(&0), Some(&100));
+        assert_eq!(map.get(&10), Some(&120));
+        assert_eq!(map.get(&19), Some(&120));
+        assert_eq!(map.get(&40), Some(&150));
+    }
+
+    #[test]
+    fn test_get_misses_in_gap() {
+        let mut map = IntervalMap::new();
+        map.insert(AtomicInterval::closed_open(0, 10), 100).unwrap();
+        map.insert(AtomicInterval::closed(30, 40), 150).unwrap();
+        assert_eq!(map.get(&-1), None);
+        assert_eq!(map.get(&10), None);
+        assert_eq!(map.get(&25), None);
+        assert_eq!(map.get(&41), None);
+    }
+
+    #[test]
+    fn test_get_on_shared_endpoint() {
+        let mut map = IntervalMap::new();
+        map.insert(AtomicInterval::closed(1, 5), 'a').unwrap();
+        map.insert(AtomicInterval::open(5, 9), 'b').unwrap();
+        assert_eq!(map.get(&5), Some(&'a'));
+        assert_eq!(map.get(&6), Some(&'b'));
+    }
+}
@@ -0,0 +1,243 @@
+//! A module containing the numeric traits required by the overflow-aware and discrete methods of the library.
+//!
+//! # Examples
+//! ```
+//! use timekeep_rs::AtomicInterval;
+//!
+//! let interval = AtomicInterval::closed(-1, i32::MAX);
+//! assert_eq!(interval.checked_length(), None);
+//! ```
+//!
+//! The numeric methods of [`AtomicInterval`](crate::AtomicInterval) and [`IntervalSet`](crate::IntervalSet)
+//! are bounded by the `core::ops` traits they need, such as `Add` and `Sub`, rather than by a single numeric
+//! trait. Each method is therefore available for every type providing its operations, such as the numeric
+//! primitives or big integers; the few methods needing more, such as a conversion to `f64`, list it in the
+//! bounds of their impl block.
+//! The optional `num-bigint` feature implements [`CheckedOps`] and [`AsF64`] for the big integers of `num-bigint`,
+//! enabling the overflow-aware and ratio methods for them.
+//!
+
+/// Arithmetic operations that report overflow instead of panicking or wrapping around.
+///
+/// It is implemented for all the primitive integer types, mirroring their inherent `checked_*` methods.
+/// With the `num-bigint` feature, it is also implemented for `num_bigint::BigInt` and `num_bigint::BigUint`
+/// through their `num_traits` checked arithmetic. Other types can implement it themselves.
+pub trait CheckedOps: Sized {
+    /// Adds two values, returning `None` on overflow.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    /// Subtracts `other` from the value, returning `None` on overflow.
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_ops {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedOps for $t {
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *other)
+                }
+
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_sub(*self, *other)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_ops!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "num-bigint")]
+macro_rules! impl_checked_ops_with_num_traits {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedOps for $t {
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    num_traits::CheckedAdd::checked_add(self, other)
+                }
+
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    num_traits::CheckedSub::checked_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "num-bigint")]
+impl_checked_ops_with_num_traits!(num_bigint::BigInt, num_bigint::BigUint);
+
+/// A lossy conversion to `f64`, used by the methods computing ratios of lengths, such as
+/// [`IntervalSet::jaccard`](crate::IntervalSet::jaccard).
+///
+/// Unlike `Into<f64>`, it is implemented for all the primitive numeric types, including the 64-bit and
+/// 128-bit integers, which may lose precision. With the `chrono` feature, it is also implemented for
+/// `chrono::Duration`, and with the `num-bigint` feature for its big integers.
+pub trait AsF64 {
+    /// Converts the value to the nearest `f64`.
+    fn as_f64(&self) -> f64;
+}
+
+macro_rules! impl_as_f64 {
+    ($($t:ty),*) => {
+        $(
+            impl AsF64 for $t {
+                fn as_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_as_f64!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+#[cfg(feature = "num-bigint")]
+macro_rules! impl_as_f64_with_num_traits {
+    ($($t:ty),*) => {
+        $(
+            impl AsF64 for $t {
+                fn as_f64(&self) -> f64 {
+                    num_traits::ToPrimitive::to_f64(self).unwrap_or(f64::NAN)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "num-bigint")]
+impl_as_f64_with_num_traits!(num_bigint::BigInt, num_bigint::BigUint);
+
+/// Types whose values are discrete, so that every value has a well-defined successor and predecessor.
+///
+/// It is implemented for all the primitive integer types and for `char`.
+pub trait Discrete: Sized {
+    /// Returns the value right after this one, or `None` if this is the greatest value.
+    fn successor(&self) -> Option<Self>;
+
+    /// Returns the value right before this one, or `None` if this is the smallest value.
+    fn predecessor(&self) -> Option<Self>;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                fn successor(&self) -> Option<Self> {
+                    <$t>::checked_add(*self, 1)
+                }
+
+                fn predecessor(&self) -> Option<Self> {
+                    <$t>::checked_sub(*self, 1)
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Characters are ordered by code point, skipping the surrogate code points, which are not valid characters.
+impl Discrete for char {
+    fn successor(&self) -> Option<Self> {
+        match *self {
+            '\u{D7FF}' => Some('\u{E000}'),
+            c => char::from_u32(c as u32 + 1),
+        }
+    }
+
+    fn predecessor(&self) -> Option<Self> {
+        match *self {
+            '\u{E000}' => Some('\u{D7FF}'),
+            c => (c as u32).checked_sub(1).and_then(char::from_u32),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_ops() {
+        assert_eq!(CheckedOps::checked_add(&1i32, &2), Some(3));
+        assert_eq!(CheckedOps::checked_add(&i32::MAX, &1), None);
+        assert_eq!(CheckedOps::checked_sub(&0u8, &1), None);
+        assert_eq!(CheckedOps::checked_sub(&5u8, &1), Some(4));
+    }
+
+    #[test]
+    fn test_as_f64() {
+        assert_eq!(3i64.as_f64(), 3.0);
+        assert_eq!(u128::MAX.as_f64(), 2f64.powi(128));
+        assert_eq!(1.5f32.as_f64(), 1.5);
+        assert_eq!((-2isize).as_f64(), -2.0);
+    }
+
+    #[test]
+    fn test_discrete() {
+        assert_eq!(5i32.successor(), Some(6));
+        assert_eq!(5i32.predecessor(), Some(4));
+        assert_eq!(i64::MAX.successor(), None);
+        assert_eq!(0u32.predecessor(), None);
+    }
+
+    #[test]
+    fn test_discrete_char() {
+        assert_eq!('a'.successor(), Some('b'));
+        assert_eq!('b'.predecessor(), Some('a'));
+        assert_eq!('\u{D7FF}'.successor(), Some('\u{E000}'));
+        assert_eq!('\u{E000}'.predecessor(), Some('\u{D7FF}'));
+        assert_eq!(char::MAX.successor(), None);
+        assert_eq!('\0'.predecessor(), None);
+    }
+
+    /// Exercises the numeric methods through a `num_traits::Num` bound only, as generic callers would.
+    #[cfg(feature = "num-bigint")]
+    fn numeric_methods<T: num_traits::Num + Clone + PartialOrd + From<u8> + core::fmt::Debug>() {
+        use crate::AtomicInterval;
+
+        let two = T::one() + T::one();
+        let ten: T = T::from(10);
+        let interval = AtomicInterval::closed(two.clone(), ten.clone());
+        assert_eq!(interval.length(), T::from(8));
+        assert_eq!(interval.midpoint(), T::from(6));
+        assert_eq!(interval.expand(two.clone()), AtomicInterval::closed(T::zero(), T::from(12)));
+    }
+
+    #[test]
+    fn test_numeric_methods_on_i64() {
+        use crate::AtomicInterval;
+
+        let interval = AtomicInterval::closed(-4i64, 6);
+        assert_eq!(interval.length(), 10);
+        assert_eq!(interval.midpoint(), 1);
+        assert_eq!(interval.expand(1), AtomicInterval::closed(-5, 7));
+        assert_eq!(interval.checked_length(), Some(10));
+        assert_eq!(AtomicInterval::closed(i64::MIN, 0).checked_length(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn test_numeric_methods_on_bignum() {
+        use crate::{AtomicInterval, IntervalSet};
+        use num_bigint::BigInt;
+
+        numeric_methods::<i64>();
+        numeric_methods::<BigInt>();
+
+        // Big integers never overflow, so the checked methods always succeed.
+        let huge = BigInt::from(i128::MAX) * BigInt::from(i128::MAX);
+        let interval = AtomicInterval::closed(-huge.clone(), huge.clone());
+        assert_eq!(interval.checked_length(), Some(&huge * 2));
+        assert_eq!(interval.checked_midpoint(), Some(BigInt::from(0)));
+        assert_eq!(CheckedOps::checked_add(&huge, &huge), Some(&huge * 2));
+        assert_eq!(huge.as_f64(), (i128::MAX as f64).powi(2));
+        let zero = num_bigint::BigUint::from(0u8);
+        assert_eq!(CheckedOps::checked_sub(&zero, &num_bigint::BigUint::from(1u8)), None);
+
+        let set = IntervalSet::from(interval).coalesce(BigInt::from(0));
+        assert_eq!(set.total_length(), &huge * 2);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+//! A module re-exporting the types and traits needed by most users of the library.
+//!
+//! # Examples
+//! ```
+//! use timekeep_rs::prelude::*;
+//!
+//! let interval = AtomicInterval::closed(1, 5);
+//! let set = IntervalSet::from(interval.clone());
+//!
+//! assert_eq!(*interval.left(), Bound::Included(1));
+//! assert!(IntervalLike::contains(&set, &3));
+//! assert_eq!(interval.relate(&AtomicInterval::closed(5, 8)), AllenRelation::Overlaps);
+//! assert_eq!(AtomicInterval::new(Bound::Included(5), Bound::Included(1)), Err(IntervalError::InvalidBounds));
+//! ```
+//!
+pub use crate::atomic::{AtomicInterval, MergeResult};
+pub use crate::bound::{Bound, Side};
+pub use crate::error::IntervalError;
+pub use crate::interval_like::IntervalLike;
+pub use crate::map::IntervalMap;
+pub use crate::num::{AsF64, CheckedOps, Discrete};
+pub use crate::relation::{AllenRelation, PointRelation};
+pub use crate::set::{IntervalSet, IntervalSetBuilder, Segment};
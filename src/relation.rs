@@ -0,0 +1,127 @@
+//! A module containing the relations that can hold between intervals.
+//! 
+//! # Examples
+//! ```
+//! use timekeep_rs::{AllenRelation, AtomicInterval};
+//! 
+//! let morning = AtomicInterval::closed_open(8, 12);
+//! let afternoon = AtomicInterval::closed_open(12, 18);
+//! assert_eq!(morning.relate(&afternoon), AllenRelation::Meets);
+//! ```
+//!
+//! A single value can be related to an interval as well:
+//! ```
+//! use timekeep_rs::{AtomicInterval, PointRelation};
+//!
+//! assert_eq!(AtomicInterval::closed_open(8, 12).relate_point(&12), Some(PointRelation::OnRightBound));
+//! ```
+//!
+
+/// Represents the relation between two intervals, following Allen's interval algebra.
+/// Exactly one of the thirteen relations holds between any two intervals.
+/// 
+/// The relations are described from the point of view of the first interval `a` against the second interval `b`.
+/// Intervals sharing a single included point overlap, while intervals touching without sharing a point meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AllenRelation {
+    /// `a` ends before `b` starts, with a gap between them.
+    Before,
+    /// `a` ends exactly where `b` starts, without sharing any point.
+    Meets,
+    /// `a` starts before `b` and ends within `b`.
+    Overlaps,
+    /// `a` and `b` start together, and `a` ends first.
+    Starts,
+    /// `a` lies strictly within `b`.
+    During,
+    /// `a` and `b` end together, and `a` starts last.
+    Finishes,
+    /// `a` and `b` have the same bounds.
+    Equals,
+    /// `a` and `b` end together, and `a` starts first. Inverse of `Finishes`.
+    FinishedBy,
+    /// `b` lies strictly within `a`. Inverse of `During`.
+    Contains,
+    /// `a` and `b` start together, and `a` ends last. Inverse of `Starts`.
+    StartedBy,
+    /// `b` starts before `a` and ends within `a`. Inverse of `Overlaps`.
+    OverlappedBy,
+    /// `a` starts exactly where `b` ends, without sharing any point. Inverse of `Meets`.
+    MetBy,
+    /// `a` starts after `b` ends, with a gap between them. Inverse of `Before`.
+    After,
+}
+
+impl AllenRelation {
+    /// Returns the relation holding in the opposite direction, i.e. of `b` against `a`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use timekeep_rs::AllenRelation;
+    ///
+    /// assert_eq!(AllenRelation::Before.inverse(), AllenRelation::After);
+    /// assert_eq!(AllenRelation::Equals.inverse(), AllenRelation::Equals);
+    /// ```
+    pub fn inverse(&self) -> AllenRelation {
+        match self {
+            AllenRelation::Before => AllenRelation::After,
+            AllenRelation::Meets => AllenRelation::MetBy,
+            AllenRelation::Overlaps => AllenRelation::OverlappedBy,
+            AllenRelation::Starts => AllenRelation::StartedBy,
+            AllenRelation::During => AllenRelation::Contains,
+            AllenRelation::Finishes => AllenRelation::FinishedBy,
+            AllenRelation::Equals => AllenRelation::Equals,
+            AllenRelation::FinishedBy => AllenRelation::Finishes,
+            AllenRelation::Contains => AllenRelation::During,
+            AllenRelation::StartedBy => AllenRelation::Starts,
+            AllenRelation::OverlappedBy => AllenRelation::Overlaps,
+            AllenRelation::MetBy => AllenRelation::Meets,
+            AllenRelation::After => AllenRelation::Before,
+        }
+    }
+}
+
+/// Represents where a value lies relative to an interval.
+///
+/// A value equal to a bound lies on that bound whether the bound is included or excluded,
+/// so `OnLeftBound` and `OnRightBound` do not imply that the interval contains the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointRelation {
+    /// The value is less than the left bound.
+    Before,
+    /// The value is equal to the left bound.
+    OnLeftBound,
+    /// The value lies strictly between the bounds.
+    Inside,
+    /// The value is equal to the right bound.
+    OnRightBound,
+    /// The value is greater than the right bound.
+    After,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inverse_is_involution() {
+        let relations = [
+            AllenRelation::Before,
+            AllenRelation::Meets,
+            AllenRelation::Overlaps,
+            AllenRelation::Starts,
+            AllenRelation::During,
+            AllenRelation::Finishes,
+            AllenRelation::Equals,
+            AllenRelation::FinishedBy,
+            AllenRelation::Contains,
+            AllenRelation::StartedBy,
+            AllenRelation::OverlappedBy,
+            AllenRelation::MetBy,
+            AllenRelation::After,
+        ];
+        for relation in relations {
+            assert_eq!(relation.inverse().inverse(), relation);
+        }
+    }
+}
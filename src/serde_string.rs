@@ -0,0 +1,123 @@
+//! A module for (de)serializing intervals with [`serde`] as their textual representation.
+//! It is only available with the `serde-string` feature enabled.
+//! 
+//! An interval is written as its `Display` form, such as `"[1, 5)"`, and read back through its
+//! `FromStr` implementation. Use it on a field with `#[serde(with = "timekeep_rs::serde_string")]`.
+//! 
+//! # Examples
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use timekeep_rs::AtomicInterval;
+//! 
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Config {
+//!     #[serde(with = "timekeep_rs::serde_string")]
+//!     window: AtomicInterval<i32>,
+//! }
+//! 
+//! let config = Config { window: AtomicInterval::closed_open(1, 5) };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, r#"{"window":"[1, 5)"}"#);
+//! assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+//! ```
+//!
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+
+use crate::AtomicInterval;
+
+/// Serializes an interval as its textual representation.
+///
+/// # Arguments
+/// * `interval` - The interval to serialize
+/// * `serializer` - The serializer to write the string to
+///
+/// # Returns
+/// The serializer's output, or its error
+pub fn serialize<T, S>(interval: &AtomicInterval<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: fmt::Display,
+    S: Serializer,
+{
+    serializer.collect_str(interval)
+}
+
+/// Deserializes an interval from its textual representation.
+///
+/// # Arguments
+/// * `deserializer` - The deserializer to read the string from
+///
+/// # Returns
+/// The parsed `AtomicInterval`, or an error if the string is not a valid interval
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<AtomicInterval<T>, D::Error>
+where
+    T: FromStr + PartialOrd + Clone,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(IntervalVisitor(PhantomData))
+}
+
+/// A visitor that parses an `AtomicInterval` from a string.
+struct IntervalVisitor<T>(PhantomData<T>);
+
+impl<'de, T: FromStr + PartialOrd + Clone> Visitor<'de> for IntervalVisitor<T> {
+    type Value = AtomicInterval<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string representing an interval, such as \"[1, 5)\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Wrapper<T: fmt::Display + FromStr + PartialOrd + Clone> {
+        #[serde(with = "crate::serde_string")]
+        window: AtomicInterval<T>,
+    }
+
+    #[test]
+    fn test_serialize_as_string() {
+        let wrapper = Wrapper { window: AtomicInterval::closed_open(1, 5) };
+        let value = serde_json::to_value(&wrapper).unwrap();
+        assert!(value["window"].is_string());
+        assert_eq!(value["window"], "[1, 5)");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let intervals = [
+            AtomicInterval::closed(1, 5),
+            AtomicInterval::open(-3, 0),
+            AtomicInterval::open_closed(2, 4),
+            AtomicInterval::point(7),
+        ];
+        for window in intervals {
+            let wrapper = Wrapper { window };
+            let json = serde_json::to_string(&wrapper).unwrap();
+            assert_eq!(serde_json::from_str::<Wrapper<i32>>(&json).unwrap(), wrapper);
+        }
+
+        let wrapper = Wrapper { window: AtomicInterval::closed_open(0.5, 2.25) };
+        let json = serde_json::to_string(&wrapper).unwrap();
+        assert_eq!(serde_json::from_str::<Wrapper<f64>>(&json).unwrap(), wrapper);
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        assert!(serde_json::from_str::<Wrapper<i32>>(r#"{"window":"[5, 1]"}"#).is_err());
+        assert!(serde_json::from_str::<Wrapper<i32>>(r#"{"window":"1..5"}"#).is_err());
+        assert!(serde_json::from_str::<Wrapper<i32>>(r#"{"window":[1, 5]}"#).is_err());
+    }
+}
@@ -35,7 +35,29 @@
 //! - `T`: Represents the boundary type for intervals
 //!   - Must implement [`Clone`]
 //!   - Must implement [`PartialOrd`] for set operations
-use crate::atomic::AtomicInterval;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, BitAnd, BitOr, Div, Not, Range, RangeInclusive, Rem, Sub};
+
+use crate::atomic::{pad, AtomicInterval, MergeResult};
+use crate::bound::Bound;
+use crate::{AsF64, Discrete, IntervalError};
+
+/// Compares two values with a total order, placing values that cannot be compared with
+/// themselves (such as `NaN`) after all the others. Any other incomparable pair is equal.
+fn cmp_total<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    match (a.partial_cmp(a).is_some(), b.partial_cmp(b).is_some()) {
+        (true, true) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => Ordering::Equal,
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct IntervalSet<T> {
@@ -43,8 +65,29 @@ pub struct IntervalSet<T> {
     pub intervals: Vec<AtomicInterval<T>>,
 }
 
-impl<T: ToString> ToString for IntervalSet<T> {
-    /// Converts the interval set to a string representation.
+/// A segment of the span of an interval set, as yielded by [`IntervalSet::segments`].
+///
+/// # Examples
+/// ```
+/// use timekeep_rs::{AtomicInterval, IntervalSet, Segment};
+///
+/// let set = IntervalSet::from(AtomicInterval::closed(1, 3));
+/// assert_eq!(set.segments().next(), Some(Segment::Covered(AtomicInterval::closed(1, 3))));
+/// ```
+///
+#[derive(PartialEq, Debug, Clone)]
+pub enum Segment<T> {
+    /// An atomic interval covered by the set.
+    Covered(AtomicInterval<T>),
+    /// An atomic interval between two covered ones, not covered by the set.
+    Gap(AtomicInterval<T>),
+}
+
+impl<T: fmt::Display> fmt::Display for IntervalSet<T> {
+    /// Formats the interval set as a string.
+    ///
+    /// A requested precision is applied to every bound value. The set is written straight into the
+    /// formatter, unless a width is requested, in which case the whole rendered set is padded and aligned.
     ///
     /// # Examples
     ///
@@ -54,16 +97,32 @@ impl<T: ToString> ToString for IntervalSet<T> {
     ///
     /// let interval = IntervalSet::from(AtomicInterval::closed(1, 5));
     /// assert_eq!(interval.to_string(), "[[1, 5]]");
+    /// assert_eq!(format!("{:<10}|", interval), "[[1, 5]]  |");
     /// ```
-    fn to_string(&self) -> String {
-        let mut result = String::from("[");
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        match f.width() {
+            Some(width) => {
+                // The rendered length is needed to pad, so only then render into a `String` first.
+                let mut rendered = String::new();
+                self.render(&mut rendered, precision)?;
+                pad(f, &rendered, width)
+            }
+            None => self.render(f, precision),
+        }
+    }
+}
+
+impl<T: fmt::Display> IntervalSet<T> {
+    /// Writes the textual representation of the interval set into `w`, formatting every bound value
+    /// with `precision` if one is given.
+    fn render<W: fmt::Write>(&self, w: &mut W, precision: Option<usize>) -> fmt::Result {
+        write!(w, "[")?;
         for interval in &self.intervals {
-            result.push_str(&interval.to_string());
+            interval.render(w, precision)?;
         }
-        result.push_str("]");
-        result
+        write!(w, "]")
     }
-    
 }
 
 impl<T: Clone> IntervalSet<T> {
@@ -96,9 +155,107 @@ impl<T: Clone> IntervalSet<T> {
     /// assert!(interval.is_empty());
     /// ```
     pub fn new() -> IntervalSet<T> {
-        return IntervalSet { intervals: vec![] }
+        IntervalSet { intervals: vec![] }
+    }
+
+    /// Retains only the intervals for which the predicate returns `true`, mirroring [`Vec::retain`].
+    ///
+    /// Removing intervals cannot create overlaps, so the set stays normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The predicate deciding whether to keep each interval
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let mut set = IntervalSet::from(AtomicInterval::closed(1, 2))
+    ///     .union(&IntervalSet::from(AtomicInterval::closed(5, 9)));
+    /// set.retain(|interval| interval.length() >= 2);
+    ///
+    /// assert_eq!(set.intervals, vec![AtomicInterval::closed(5, 9)]);
+    /// ```
+    pub fn retain<F: FnMut(&AtomicInterval<T>) -> bool>(&mut self, f: F) {
+        self.intervals.retain(f);
+    }
+
+    /// Returns an iterator over the intervals of the set in descending order, from last to first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(1, 2))
+    ///     .union(&IntervalSet::from(AtomicInterval::closed(5, 9)));
+    /// let mut iter = set.iter_rev();
+    ///
+    /// assert_eq!(iter.next(), Some(&AtomicInterval::closed(5, 9)));
+    /// assert_eq!(iter.next(), Some(&AtomicInterval::closed(1, 2)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter_rev(&self) -> impl Iterator<Item = &AtomicInterval<T>> {
+        self.intervals.iter().rev()
+    }
+
+    /// Returns an iterator over the pairs of consecutive intervals of the set, in order.
+    ///
+    /// On a normalized set, each pair delimits a gap, between the right bound of the first interval
+    /// and the left bound of the second.
+    /// An empty set or a set with a single interval yields no pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(vec![AtomicInterval::closed(1, 2), AtomicInterval::closed(5, 9), AtomicInterval::closed(10, 11)]);
+    /// let gaps: Vec<i32> = set.pairs().map(|(a, b)| b.left().value() - a.right().value()).collect();
+    ///
+    /// assert_eq!(gaps, vec![3, 1]);
+    /// ```
+    pub fn pairs(&self) -> impl Iterator<Item = (&AtomicInterval<T>, &AtomicInterval<T>)> {
+        self.intervals.windows(2).map(|pair| (&pair[0], &pair[1]))
+    }
+
+    /// Creates an interval set from atomic intervals that are already sorted and disjoint, without checking them.
+    ///
+    /// This skips the cost of normalization, which is useful when the intervals come from an
+    /// already ordered source. Passing unsorted, overlapping or adjacent intervals does not fail,
+    /// but breaks the invariant the other operations rely on and makes their results unspecified.
+    /// Use [`IntervalSet::from_sorted`] to have the input checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `atoms` - The sorted, disjoint and non-adjacent intervals of the set
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from_sorted_unchecked(vec![
+    ///     AtomicInterval::closed(1, 3),
+    ///     AtomicInterval::closed(5, 8),
+    /// ]);
+    /// assert_eq!(set.intervals.len(), 2);
+    /// ```
+    pub fn from_sorted_unchecked(atoms: Vec<AtomicInterval<T>>) -> Self {
+        IntervalSet { intervals: atoms }
     }
+}
 
+impl<T: Clone> Default for IntervalSet<T> {
+    /// Returns an empty `IntervalSet`, same as [`IntervalSet::new`].
+    fn default() -> Self {
+        IntervalSet::new()
+    }
 }
 
 impl<T: Clone> From<AtomicInterval<T>> for IntervalSet<T> {
@@ -123,21 +280,121 @@ impl<T: Clone> From<AtomicInterval<T>> for IntervalSet<T> {
     }
 }
 
+impl<T: PartialOrd + Clone> From<Vec<AtomicInterval<T>>> for IntervalSet<T> {
+    /// Creates a new `IntervalSet<T>` from a `Vec` of `AtomicInterval<T>`.
+    ///
+    /// The intervals may be given in any order and may overlap, as the resulting set is normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(vec![AtomicInterval::closed(4, 7), AtomicInterval::closed(1, 5)]);
+    /// assert_eq!(set.intervals, vec![AtomicInterval::closed(1, 7)]);
+    /// ```
+    fn from(intervals: Vec<AtomicInterval<T>>) -> Self {
+        IntervalSet { intervals }.normalize()
+    }
+}
+
+impl<T: PartialOrd + Clone> From<Range<T>> for IntervalSet<T> {
+    /// Creates a new `IntervalSet<T>` from a half-open `Range<T>`, as a left-closed, right-open interval.
+    ///
+    /// An empty range, such as `5..5`, produces an empty set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(1..5);
+    /// assert_eq!(set.intervals, vec![AtomicInterval::closed_open(1, 5)]);
+    /// assert!(IntervalSet::from(5..5).is_empty());
+    /// ```
+    fn from(range: Range<T>) -> Self {
+        match AtomicInterval::new(Bound::Included(range.start), Bound::Excluded(range.end)) {
+            Ok(interval) => IntervalSet::from(interval),
+            Err(_) => IntervalSet::new(),
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone> From<RangeInclusive<T>> for IntervalSet<T> {
+    /// Creates a new `IntervalSet<T>` from a `RangeInclusive<T>`, as a closed interval.
+    ///
+    /// An empty range, such as `5..=4`, produces an empty set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(1..=5);
+    /// assert_eq!(set.intervals, vec![AtomicInterval::closed(1, 5)]);
+    /// assert!(IntervalSet::from(5..=4).is_empty());
+    /// ```
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        match AtomicInterval::new(Bound::Included(start), Bound::Included(end)) {
+            Ok(interval) => IntervalSet::from(interval),
+            Err(_) => IntervalSet::new(),
+        }
+    }
+}
+
 /// A trait implementation for `IntervalSet<T>` where `T` implements `PartialOrd` and `Clone`.
 /// Provides set operations for interval sets.
 impl<T: PartialOrd + Clone> IntervalSet<T> {
-    /// Computes the union of two interval sets.
+    /// Creates an interval set from atomic intervals that must already be sorted and disjoint.
     ///
-    /// The union of two interval sets is a new interval set that contains all the intervals
-    /// from both input sets, merging any overlapping or adjacent intervals.
+    /// Unlike [`IntervalSet::from_sorted_unchecked`], the input is checked: every interval must
+    /// lie strictly before the next one, without overlapping or touching it.
     ///
     /// # Arguments
     ///
-    /// * `other` - Another interval set to compute the union with
+    /// * `atoms` - The sorted, disjoint and non-adjacent intervals of the set
     ///
     /// # Returns
     ///
-    /// A new `IntervalSet<T>` representing the union of both interval sets
+    /// * `Ok(IntervalSet<T>)` - If the intervals are sorted, disjoint and non-adjacent
+    /// * `Err(IntervalError::Overlapping)` - If two consecutive intervals overlap or are adjacent
+    /// * `Err(IntervalError::Unsorted)` - If two consecutive intervals are out of order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, IntervalError, IntervalSet};
+    ///
+    /// let set = IntervalSet::from_sorted(vec![AtomicInterval::closed(1, 3), AtomicInterval::closed(5, 8)]);
+    /// assert!(set.is_ok());
+    ///
+    /// let set = IntervalSet::from_sorted(vec![AtomicInterval::closed(5, 8), AtomicInterval::closed(1, 3)]);
+    /// assert_eq!(set, Err(IntervalError::Unsorted));
+    /// ```
+    pub fn from_sorted(atoms: Vec<AtomicInterval<T>>) -> Result<Self, IntervalError> {
+        for pair in atoms.windows(2) {
+            if pair[0].is_overlapping(&pair[1]) || pair[0].is_adjacent(&pair[1]) {
+                return Err(IntervalError::Overlapping);
+            }
+            if !pair[0].is_before(&pair[1]) {
+                return Err(IntervalError::Unsorted);
+            }
+        }
+        Ok(IntervalSet { intervals: atoms })
+    }
+
+    /// Inserts an atomic interval into the set in place, merging it with the intervals it overlaps or touches.
+    ///
+    /// The intervals affected by the insertion are located with a binary search, so only those
+    /// are visited, instead of re-sorting the whole set as [`IntervalSet::union`] does.
+    ///
+    /// # Arguments
+    ///
+    /// * `atom` - The interval to insert into the set
     ///
     /// # Examples
     ///
@@ -145,58 +402,107 @@ impl<T: PartialOrd + Clone> IntervalSet<T> {
     /// use timekeep_rs::AtomicInterval;
     /// use timekeep_rs::IntervalSet;
     ///
-    /// // Create two interval sets
-    /// let interval1 = IntervalSet::from(AtomicInterval::closed(1, 5));
-    /// let interval2 = IntervalSet::from(AtomicInterval::closed(3, 7));
+    /// let mut set = IntervalSet::from(AtomicInterval::closed(1, 3));
+    /// set.insert(AtomicInterval::closed(6, 8));
+    /// set.insert(AtomicInterval::closed(2, 6));
     ///
-    /// // Compute union (results in [1, 7])
-    /// let union = interval1.union(&interval2);
+    /// assert_eq!(set.intervals, vec![AtomicInterval::closed(1, 8)]);
     /// ```
-    pub fn union(&self, other: &Self) -> Self {
-        let mut intervals = self.intervals.clone();
-        intervals.extend(other.intervals.iter().cloned());
-
-        // Sort intervals by the value of their left boundary.
-        intervals.sort_by(
-            |a, b| a.left().value().partial_cmp(b.left().value()).unwrap()
-        );
+    pub fn insert(&mut self, atom: AtomicInterval<T>) {
+        // Intervals lying entirely before or after the atom, without touching it, are left untouched.
+        let start = self.intervals.partition_point(|interval| interval.is_before(&atom) && !interval.is_adjacent(&atom));
+        let end = self.intervals.partition_point(|interval| !interval.is_after(&atom) || interval.is_adjacent(&atom));
 
-        let mut merged: Vec<AtomicInterval<T>> = Vec::new();
+        let mut atom = atom;
+        if start < end {
+            let first = &self.intervals[start];
+            let last = &self.intervals[end - 1];
+            let left = match first.left().cmp_as_left(atom.left()) {
+                Ordering::Less => first.left().clone(),
+                _ => atom.left().clone(),
+            };
+            let right = match last.right().cmp_as_right(atom.right()) {
+                Ordering::Greater => last.right().clone(),
+                _ => atom.right().clone(),
+            };
+            atom = AtomicInterval::new(left, right).expect("Merging intervals cannot produce invalid bounds");
+        }
+        self.intervals.splice(start..end, core::iter::once(atom));
+    }
 
-        for interval in intervals {
-            if let Some(last) = merged.last_mut() {
-                let union_vec = AtomicInterval::union(last, &interval);
-    
-                if union_vec.len() == 1 {
-                    // Successfully merged, update last interval
-                    *last = union_vec.into_iter().next().unwrap();
-                    continue;
-                } else if union_vec.len() > 1 {
-                    // If union() returned multiple intervals, replace last and insert the new one
-                    *last = union_vec[0].clone();
-                    merged.extend(union_vec.into_iter().skip(1));
-                    continue;
-                }
-            }
-            merged.push(interval);
+    /// Removes an atomic interval from the set in place, trimming or splitting the intervals it overlaps.
+    ///
+    /// The intervals affected by the removal are located with a binary search, so only those
+    /// are visited, instead of rebuilding the whole set as [`IntervalSet::difference`] does.
+    ///
+    /// # Arguments
+    ///
+    /// * `atom` - The interval to remove from the set
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let mut set = IntervalSet::from(AtomicInterval::closed(1, 10));
+    /// set.remove(&AtomicInterval::closed(4, 6));
+    ///
+    /// assert_eq!(set.intervals, vec![AtomicInterval::closed_open(1, 4), AtomicInterval::open_closed(6, 10)]);
+    /// ```
+    pub fn remove(&mut self, atom: &AtomicInterval<T>) {
+        // Intervals lying entirely before or after the atom are left untouched.
+        let start = self.intervals.partition_point(|interval| interval.is_before(atom));
+        let end = self.intervals.partition_point(|interval| !interval.is_after(atom));
+        if start >= end {
+            return;
         }
 
-        IntervalSet { intervals: merged }
+        let remainders: Vec<_> = self.intervals[start..end]
+            .iter()
+            .flat_map(|interval| interval.difference(atom))
+            .collect();
+        self.intervals.splice(start..end, remainders);
     }
 
-    /// Computes the intersection of two interval sets.
+    /// Checks if the interval set is normalized, as produced by the set operations.
     ///
-    /// The intersection of two interval sets is a new interval set that contains all the intervals
-    /// that are common to both input sets.
+    /// A normalized interval set only holds valid intervals, sorted in ascending order,
+    /// with no two of them overlapping or touching.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the interval set is normalized, `false` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet {
+    ///     intervals: vec![AtomicInterval::closed(4, 7), AtomicInterval::closed(1, 5)],
+    /// };
+    /// assert!(!set.is_normalized());
+    /// assert!(set.normalize().is_normalized());
+    /// ```
+    pub fn is_normalized(&self) -> bool {
+        self.intervals.iter().all(|interval| interval.is_valid())
+            && self.intervals.windows(2).all(|pair| pair[0].is_before(&pair[1]) && !pair[0].is_adjacent(&pair[1]))
+    }
+
+    /// Checks if two interval sets denote the same points, however their intervals are laid out.
+    ///
+    /// Unlike the derived `PartialEq`, which compares the intervals one by one, this normalizes
+    /// both sets first, so that overlapping, touching or unsorted intervals do not matter.
     ///
     /// # Arguments
     ///
-    /// * `other` - Another interval set to compute the intersection with
+    /// * `other` - Another interval set to compare with
     ///
     /// # Returns
     ///
-    /// * `Some(IntervalSet<T>)` if the interval sets intersect
-    /// * `None` if the interval sets are disjoint
+    /// `true` if both sets denote the same points, `false` otherwise
     ///
     /// # Examples
     ///
@@ -204,51 +510,88 @@ impl<T: PartialOrd + Clone> IntervalSet<T> {
     /// use timekeep_rs::AtomicInterval;
     /// use timekeep_rs::IntervalSet;
     ///
-    /// // Create two interval sets
-    /// let interval1 = IntervalSet::from(AtomicInterval::closed(1, 5));
-    /// let interval2 = IntervalSet::from(AtomicInterval::closed(3, 7));
+    /// let split = IntervalSet {
+    ///     intervals: vec![AtomicInterval::closed(1, 3), AtomicInterval::closed(3, 5)],
+    /// };
+    /// let whole = IntervalSet::from(AtomicInterval::closed(1, 5));
     ///
-    /// // Compute intersection (results in [3, 5])
-    /// let intersection = interval1.intersection(&interval2);
+    /// assert_ne!(split, whole);
+    /// assert!(split.equals_pointset(&whole));
     /// ```
-    pub fn intersection(&self, other: &Self) -> Self {
-        let mut intervals = Vec::new();
-
-        for interval in &self.intervals {
-            for other_interval in &other.intervals {
-                interval.intersection(other_interval).iter().for_each(
-                    |x| intervals.push(x.clone())
-                );
-                // if intersection_vec.len() > 1 {
-                //     panic!("Unexpected behavior from intersection.")
-                // } else if intersection_vec.len() == 1 {
-                //     intervals.push(intersection);
-
-                // }
-                // if let Some(intersection) = interval.intersection(other_interval) {
-                //     intervals.push(intersection);
-                // }
-            }
-        }
+    pub fn equals_pointset(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
 
-        if intervals.is_empty() {
-            IntervalSet::new()
+    /// Finds the interval of the set containing a value, with a binary search over the left bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to look for
+    ///
+    /// # Returns
+    ///
+    /// * `Some(index)` of the interval in `intervals` that contains the value
+    /// * `None` if no interval contains the value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+    ///     .union(&IntervalSet::from(AtomicInterval::closed(5, 8)));
+    /// assert_eq!(set.find(&6), Some(1));
+    /// assert_eq!(set.find(&4), None);
+    /// ```
+    pub fn find(&self, value: &T) -> Option<usize> {
+        // The only candidate is the last interval starting at or before the value.
+        let index = self.intervals.partition_point(|interval| interval.left().value() <= value);
+        let candidate = index.checked_sub(1)?;
+        if self.intervals[candidate].contains(value) {
+            Some(candidate)
         } else {
-            IntervalSet { intervals }
+            None
         }
     }
 
-    /// Computes the difference between two interval sets.
+    /// Checks if any interval of the set contains a value, in logarithmic time.
     ///
-    /// The difference A - B contains all points that are in A but not in B.
+    /// # Arguments
+    ///
+    /// * `value` - The value to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value lies within the set, `false` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+    ///     .union(&IntervalSet::from(AtomicInterval::open(5, 8)));
+    /// assert!(set.contains(&2));
+    /// assert!(!set.contains(&5));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        self.find(value).is_some()
+    }
+
+    /// Computes the union of two interval sets.
+    ///
+    /// The union of two interval sets is a new interval set that contains all the intervals
+    /// from both input sets, merging any overlapping or adjacent intervals.
     ///
     /// # Arguments
     ///
-    /// * `other` - Another interval set to subtract from this interval set
+    /// * `other` - Another interval set to compute the union with
     ///
     /// # Returns
     ///
-    /// A new `IntervalSet<T>` representing the difference between the interval sets
+    /// A new `IntervalSet<T>` representing the union of both interval sets
     ///
     /// # Examples
     ///
@@ -260,105 +603,2850 @@ impl<T: PartialOrd + Clone> IntervalSet<T> {
     /// let interval1 = IntervalSet::from(AtomicInterval::closed(1, 5));
     /// let interval2 = IntervalSet::from(AtomicInterval::closed(3, 7));
     ///
-    /// // Compute difference (results in [1, 3))
-    /// let difference = interval1.difference(&interval2);
+    /// // Compute union (results in [1, 7])
+    /// let union = interval1.union(&interval2);
     /// ```
-    pub fn difference(&self, other: &Self) -> Self {
-        let mut result = Vec::new();
+    ///
+    /// Bounds that cannot be compared, such as a `NaN` float bound, do not cause a panic; see
+    /// [`IntervalSet::normalize`] for where such intervals end up. Use [`IntervalSet::try_union`]
+    /// to reject them with an error instead.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut intervals = self.intervals.clone();
+        intervals.extend(other.intervals.iter().cloned());
 
-        for interval in &self.intervals {
-            let mut remaining = vec![interval.clone()];
-            for other_interval in &other.intervals {
-                let mut new_remaining = Vec::new();
-                for part in remaining {
-                    new_remaining.extend(part.difference(other_interval));
-                }
-                remaining = new_remaining;
-            }
-            result.extend(remaining);
+        let result = IntervalSet { intervals }.normalize();
+        debug_assert!(result.is_normalized_or_incomparable());
+        result
+    }
+
+    /// Computes the union of two interval sets like [`IntervalSet::union`], failing instead of
+    /// panicking when a bound cannot be compared.
+    ///
+    /// A bound is incomparable when its value cannot be compared with itself, as is the case for `NaN` floats.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Another interval set to compute the union with
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(IntervalSet<T>)` - The union of both interval sets
+    /// * `Err(IntervalError::IncomparableBound)` - If a bound of either set cannot be compared
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, IntervalError, IntervalSet};
+    ///
+    /// let a = IntervalSet::from(AtomicInterval::closed(1.0, 5.0));
+    /// let b = IntervalSet::from(AtomicInterval::point(f64::NAN));
+    ///
+    /// assert_eq!(a.try_union(&a), Ok(a.clone()));
+    /// assert_eq!(a.try_union(&b), Err(IntervalError::IncomparableBound));
+    /// ```
+    pub fn try_union(&self, other: &Self) -> Result<Self, IntervalError> {
+        if !self.has_comparable_bounds() || !other.has_comparable_bounds() {
+            return Err(IntervalError::IncomparableBound);
+        }
+        Ok(self.union(other))
+    }
+
+    /// Checks the invariant of the results of set operations: they are normalized, unless some
+    /// bound cannot be compared, in which case normalization cannot be achieved.
+    fn is_normalized_or_incomparable(&self) -> bool {
+        self.is_normalized() || !self.has_comparable_bounds()
+    }
+
+    /// Checks if the value of every bound of the set can be compared, ruling out values like `NaN`.
+    fn has_comparable_bounds(&self) -> bool {
+        self.intervals.iter().all(|interval| {
+            let (left, right) = (interval.left().value(), interval.right().value());
+            left.partial_cmp(left).is_some() && right.partial_cmp(right).is_some()
+        })
+    }
+
+    /// Normalizes the interval set.
+    ///
+    /// A normalized interval set has its intervals sorted by left bound, with any overlapping
+    /// or adjacent intervals merged, so that no two intervals overlap or touch.
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalSet<T>` denoting the same points, in normalized form
+    ///
+    /// Intervals whose left bound cannot be compared with itself, such as a `NaN` float, are
+    /// sorted after all the others, keeping their original relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet {
+    ///     intervals: vec![AtomicInterval::closed(4, 7), AtomicInterval::closed(1, 5)],
+    /// };
+    /// assert_eq!(set.normalize().intervals, vec![AtomicInterval::closed(1, 7)]);
+    /// ```
+    pub fn normalize(self) -> Self {
+        let mut intervals = self.intervals;
+
+        // Sort intervals by their left boundary, included boundaries first on ties.
+        intervals.sort_by(
+            |a, b| cmp_total(a.left().value(), b.left().value()).then_with(
+                || match (a.left(), b.left()) {
+                    (Bound::Included(_), Bound::Excluded(_)) => Ordering::Less,
+                    (Bound::Excluded(_), Bound::Included(_)) => Ordering::Greater,
+                    (_, _) => Ordering::Equal,
+                }
+            )
+        );
+
+        let mut merged: Vec<AtomicInterval<T>> = Vec::new();
+
+        for interval in intervals {
+            if let Some(last) = merged.last_mut() {
+                let union_vec = AtomicInterval::union(last, &interval);
+    
+                if union_vec.len() == 1 {
+                    // Successfully merged, update last interval
+                    *last = union_vec.into_iter().next().unwrap();
+                    continue;
+                } else if union_vec.len() > 1 {
+                    // If union() returned multiple intervals, replace last and insert the new one
+                    *last = union_vec[0].clone();
+                    merged.extend(union_vec.into_iter().skip(1));
+                    continue;
+                }
+            }
+            merged.push(interval);
+        }
+
+        IntervalSet { intervals: merged }
+    }
+
+    /// Borrows the interval set if it is normalized, or returns a normalized copy of it otherwise.
+    fn normalized(&self) -> Cow<'_, Self> {
+        if self.is_normalized() {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.clone().normalize())
+        }
+    }
+
+    /// Normalizes the interval set in place, if it is not normalized already.
+    fn normalize_in_place(&mut self) {
+        if !self.is_normalized() {
+            *self = core::mem::take(self).normalize();
+        }
+    }
+
+    /// Flattens an interval set whose intervals may be duplicated or nested, as may happen
+    /// when its `intervals` field was filled from an untrusted source.
+    ///
+    /// This is [`IntervalSet::normalize`], merging duplicated, nested, overlapping and adjacent intervals.
+    /// Flattening is idempotent: flattening an already flattened set returns it unchanged.
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalSet<T>` denoting the same points, in normalized form
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet {
+    ///     intervals: vec![AtomicInterval::closed(1, 5), AtomicInterval::closed(2, 3), AtomicInterval::closed(1, 5)],
+    /// };
+    /// assert_eq!(set.flatten().intervals, vec![AtomicInterval::closed(1, 5)]);
+    /// ```
+    pub fn flatten(self) -> Self {
+        self.normalize()
+    }
+
+    /// Consumes the interval set, returning its atomic intervals in normalized form.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of non-overlapping, non-adjacent `AtomicInterval<T>` sorted by left bound
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(vec![AtomicInterval::closed(4, 7), AtomicInterval::closed(1, 5)]);
+    /// assert_eq!(set.into_atoms(), vec![AtomicInterval::closed(1, 7)]);
+    /// ```
+    pub fn into_atoms(self) -> Vec<AtomicInterval<T>> {
+        self.normalize().intervals
+    }
+
+    /// Returns a copy of the atomic intervals of the set in normalized form.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of non-overlapping, non-adjacent `AtomicInterval<T>` sorted by left bound
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(1, 5));
+    /// assert_eq!(set.to_atoms(), vec![AtomicInterval::closed(1, 5)]);
+    /// ```
+    pub fn to_atoms(&self) -> Vec<AtomicInterval<T>> {
+        self.normalized().into_owned().intervals
+    }
+
+    /// Converts the interval set into inclusive ranges, one per atomic interval in normalized form.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<RangeInclusive<T>>)` - The ranges, sorted in ascending order
+    /// * `Err(IntervalError::ExcludedBound)` - If an interval has an excluded bound, which an inclusive range cannot express
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, IntervalError, IntervalSet};
+    ///
+    /// let set = IntervalSet::from(vec![AtomicInterval::closed(1, 3), AtomicInterval::point(7)]);
+    /// assert_eq!(set.to_ranges_inclusive(), Ok(vec![1..=3, 7..=7]));
+    ///
+    /// let open = IntervalSet::from(AtomicInterval::open(1, 3));
+    /// assert_eq!(open.to_ranges_inclusive(), Err(IntervalError::ExcludedBound));
+    /// ```
+    pub fn to_ranges_inclusive(&self) -> Result<Vec<RangeInclusive<T>>, IntervalError> {
+        self.normalized()
+            .intervals
+            .iter()
+            .map(|interval| match (interval.left(), interval.right()) {
+                (Bound::Included(left), Bound::Included(right)) => Ok(left.clone()..=right.clone()),
+                (_, _) => Err(IntervalError::ExcludedBound),
+            })
+            .collect()
+    }
+
+    /// Merges overlapping or adjacent neighboring intervals in a single pass, without sorting.
+    ///
+    /// This is a cheaper alternative to [`IntervalSet::normalize`] when the intervals are known to
+    /// be sorted by left bound. Only consecutive entries are compared, so on unsorted input the set
+    /// is left denoting the same points but possibly not normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let mut set = IntervalSet {
+    ///     intervals: vec![AtomicInterval::closed(1, 4), AtomicInterval::closed(3, 6), AtomicInterval::closed(8, 9)],
+    /// };
+    /// set.merge_adjacent();
+    /// assert_eq!(set.intervals, vec![AtomicInterval::closed(1, 6), AtomicInterval::closed(8, 9)]);
+    /// ```
+    pub fn merge_adjacent(&mut self) {
+        let intervals = core::mem::take(&mut self.intervals);
+        let mut merged: Vec<AtomicInterval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            if let Some(last) = merged.last_mut() {
+                if let MergeResult::Merged(union) = last.merge(&interval) {
+                    *last = union;
+                    continue;
+                }
+            }
+            merged.push(interval);
+        }
+        self.intervals = merged;
+    }
+
+    /// Transforms every interval of the set with a function, and normalizes the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function to apply to each atomic interval
+    ///
+    /// # Returns
+    ///
+    /// A normalized `IntervalSet<T>` of the transformed intervals
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(vec![AtomicInterval::closed(1, 3), AtomicInterval::closed(5, 7)]);
+    /// let expanded = set.map_atoms(|atom| atom.expand(1));
+    ///
+    /// assert_eq!(expanded.intervals, vec![AtomicInterval::closed(0, 8)]);
+    /// ```
+    pub fn map_atoms<F: Fn(&AtomicInterval<T>) -> AtomicInterval<T>>(&self, f: F) -> Self {
+        let intervals = self.intervals.iter().map(f).collect();
+        IntervalSet { intervals }.normalize()
+    }
+
+    /// Forces the inclusivity of the bounds of every interval of the set, and normalizes the result.
+    ///
+    /// This reinterprets a set imported under another boundary convention, keeping the bound values.
+    /// Intervals that become empty, as point intervals do when a bound is excluded, are dropped,
+    /// and intervals may merge or split apart as their shared endpoints are included or excluded.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - Whether the left bounds are included
+    /// * `right` - Whether the right bounds are included
+    ///
+    /// # Returns
+    ///
+    /// A normalized `IntervalSet<T>` with the requested bound style
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(vec![AtomicInterval::closed(1, 3), AtomicInterval::point(7)]);
+    /// assert_eq!(set.reinterpret_bounds(true, false).intervals, vec![AtomicInterval::closed_open(1, 3)]);
+    /// ```
+    pub fn reinterpret_bounds(&self, left: bool, right: bool) -> Self {
+        let intervals = self
+            .intervals
+            .iter()
+            .filter_map(|interval| interval.with_inclusivity(left, right))
+            .collect();
+        IntervalSet { intervals }.normalize()
+    }
+
+    /// Maps the bounds of every interval of the set through a function, and normalizes the result.
+    ///
+    /// The function does not need to preserve order: when it maps the left bound of an interval
+    /// after its right bound, as a decreasing function does, the bounds are swapped along with
+    /// their inclusivity. An interval whose bounds map to the same value becomes a point interval.
+    /// Each interval is mapped to the interval between its mapped bounds, which is its exact image
+    /// only for monotonic functions.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function to apply to each bound value
+    ///
+    /// # Returns
+    ///
+    /// A normalized `IntervalSet<U>` of the mapped intervals
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed_open(1, 3));
+    /// assert_eq!(set.map_values(|x| -x).intervals, vec![AtomicInterval::open_closed(-3, -1)]);
+    /// ```
+    pub fn map_values<U: PartialOrd + Clone, F: Fn(&T) -> U>(&self, f: F) -> IntervalSet<U> {
+        let intervals = self
+            .intervals
+            .iter()
+            .map(|interval| {
+                let left = interval.left().clone().map(|value| f(&value));
+                let right = interval.right().clone().map(|value| f(&value));
+                let (left, right) = if left.value() > right.value() { (right, left) } else { (left, right) };
+                AtomicInterval::new(left, right)
+                    .unwrap_or_else(|_| AtomicInterval::point(f(interval.left().value())))
+            })
+            .collect();
+        IntervalSet { intervals }.normalize()
+    }
+
+    /// Computes the intersection of two interval sets.
+    ///
+    /// The intersection of two interval sets is a new interval set that contains all the intervals
+    /// that are common to both input sets. Input sets that are not normalized are normalized first.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Another interval set to compute the intersection with
+    ///
+    /// # Returns
+    ///
+    /// * `Some(IntervalSet<T>)` if the interval sets intersect
+    /// * `None` if the interval sets are disjoint
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// // Create two interval sets
+    /// let interval1 = IntervalSet::from(AtomicInterval::closed(1, 5));
+    /// let interval2 = IntervalSet::from(AtomicInterval::closed(3, 7));
+    ///
+    /// // Compute intersection (results in [3, 5])
+    /// let intersection = interval1.intersection(&interval2);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (this, other) = (self.normalized(), other.normalized());
+        let mut intervals = Vec::new();
+
+        for interval in &this.intervals {
+            for other_interval in &other.intervals {
+                interval.intersection_into(other_interval, &mut intervals);
+            }
+        }
+
+        let result = IntervalSet { intervals };
+        debug_assert!(result.is_normalized_or_incomparable());
+        result
+    }
+
+    /// Computes the intersection of the interval set with a single atomic interval, clipping the set to it.
+    ///
+    /// This is equivalent to intersecting with `IntervalSet::from(atom.clone())`, without building
+    /// that set, and only visits the intervals of the set that may overlap the atom.
+    ///
+    /// # Arguments
+    ///
+    /// * `atom` - The atomic interval to clip the set to
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalSet<T>` holding the points of the set that lie within the atom
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(vec![AtomicInterval::closed(1, 5), AtomicInterval::closed(8, 12)]);
+    /// let clipped = set.intersect_atom(&AtomicInterval::closed(3, 9));
+    ///
+    /// assert_eq!(clipped.intervals, vec![AtomicInterval::closed(3, 5), AtomicInterval::closed(8, 9)]);
+    /// ```
+    pub fn intersect_atom(&self, atom: &AtomicInterval<T>) -> Self {
+        let this = self.normalized();
+
+        // Intervals lying entirely before or after the atom cannot overlap it.
+        let start = this.intervals.partition_point(|interval| interval.is_before(atom));
+        let end = this.intervals.partition_point(|interval| !interval.is_after(atom));
+
+        let mut intervals = Vec::new();
+        for interval in this.intervals.get(start..end).unwrap_or_default() {
+            interval.intersection_into(atom, &mut intervals);
+        }
+        IntervalSet { intervals }
+    }
+
+    /// Computes the difference between two interval sets.
+    ///
+    /// The difference A - B contains all points that are in A but not in B.
+    /// Input sets that are not normalized are normalized first.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Another interval set to subtract from this interval set
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalSet<T>` representing the difference between the interval sets
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// // Create two interval sets
+    /// let interval1 = IntervalSet::from(AtomicInterval::closed(1, 5));
+    /// let interval2 = IntervalSet::from(AtomicInterval::closed(3, 7));
+    ///
+    /// // Compute difference (results in [1, 3))
+    /// let difference = interval1.difference(&interval2);
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = Vec::new();
+        // Two buffers reused across all the atoms, instead of allocating for every partial difference.
+        let mut remaining = Vec::new();
+        let mut scratch = Vec::new();
+
+        let (this, other) = (self.normalized(), other.normalized());
+        for interval in &this.intervals {
+            remaining.push(interval.clone());
+            for other_interval in &other.intervals {
+                for part in &remaining {
+                    part.difference_into(other_interval, &mut scratch);
+                }
+                remaining.clear();
+                core::mem::swap(&mut remaining, &mut scratch);
+            }
+            result.append(&mut remaining);
+        }
+
+        let result = IntervalSet { intervals: result };
+        debug_assert!(result.is_normalized_or_incomparable());
+        result
+    }
+
+    /// Computes the union of two interval sets in place, storing the result in `self`.
+    ///
+    /// The intervals of `other` are inserted one by one with [`IntervalSet::insert`],
+    /// reusing the storage of `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Another interval set to compute the union with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let mut set = IntervalSet::from(AtomicInterval::closed(1, 5));
+    /// set.union_with(&IntervalSet::from(AtomicInterval::closed(3, 7)));
+    /// assert_eq!(set.intervals, vec![AtomicInterval::closed(1, 7)]);
+    /// ```
+    pub fn union_with(&mut self, other: &Self) {
+        self.normalize_in_place();
+        for interval in &other.intervals {
+            self.insert(interval.clone());
+        }
+    }
+
+    /// Computes the intersection of two interval sets in place, storing the result in `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Another interval set to compute the intersection with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let mut set = IntervalSet::from(AtomicInterval::closed(1, 5));
+    /// set.intersection_with(&IntervalSet::from(AtomicInterval::closed(3, 7)));
+    /// assert_eq!(set.intervals, vec![AtomicInterval::closed(3, 5)]);
+    /// ```
+    pub fn intersection_with(&mut self, other: &Self) {
+        *self = self.intersection(other);
+    }
+
+    /// Computes the difference between two interval sets in place, storing the result in `self`.
+    ///
+    /// The intervals of `other` are removed one by one with [`IntervalSet::remove`],
+    /// reusing the storage of `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Another interval set to subtract from this one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let mut set = IntervalSet::from(AtomicInterval::closed(1, 5));
+    /// set.difference_with(&IntervalSet::from(AtomicInterval::closed(3, 7)));
+    /// assert_eq!(set.intervals, vec![AtomicInterval::closed_open(1, 3)]);
+    /// ```
+    pub fn difference_with(&mut self, other: &Self) {
+        self.normalize_in_place();
+        for interval in &other.intervals {
+            self.remove(interval);
+        }
+    }
+
+    /// Computes the span of the interval set.
+    ///
+    /// The span is the smallest atomic interval containing every interval of the set.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(AtomicInterval<T>)` covering the whole set
+    /// * `None` if the set is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+    ///     .union(&IntervalSet::from(AtomicInterval::open(6, 8)));
+    /// assert_eq!(set.span(), Some(AtomicInterval::closed_open(1, 8)));
+    /// ```
+    pub fn span(&self) -> Option<AtomicInterval<T>> {
+        let mut intervals = self.intervals.iter();
+        let first = intervals.next()?;
+
+        let mut left = first.left().clone();
+        let mut right = first.right().clone();
+        for interval in intervals {
+            // Keep the left bound that starts first and the right bound that ends last.
+            if left.cmp_as_left(interval.left()) == Ordering::Greater {
+                left = interval.left().clone();
+            }
+            if right.cmp_as_right(interval.right()) == Ordering::Less {
+                right = interval.right().clone();
+            }
+        }
+
+        AtomicInterval::new(left, right).ok()
+    }
+
+    /// Computes the complement of the interval set within a universe.
+    ///
+    /// The complement contains all points of the universe that are not in the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `universe` - The atomic interval to take the complement within
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalSet<T>` with the parts of `universe` not covered by the set
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(3, 5));
+    /// let complement = set.complement_within(&AtomicInterval::closed(1, 10));
+    ///
+    /// assert_eq!(complement.intervals, vec![
+    ///     AtomicInterval::closed_open(1, 3),
+    ///     AtomicInterval::open_closed(5, 10),
+    /// ]);
+    /// ```
+    pub fn complement_within(&self, universe: &AtomicInterval<T>) -> Self {
+        IntervalSet::from(universe.clone()).difference(self)
+    }
+
+    /// Computes the holes of the interval set within a window, including the leading and trailing ones.
+    ///
+    /// Unlike the `!` operator, which only yields the holes between the intervals of the set,
+    /// the holes are taken within an explicit window. Intervals of the set reaching outside
+    /// the window are clipped to it. This is the same as [`IntervalSet::complement_within`].
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The atomic interval to look for holes within
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalSet<T>` with the parts of `window` not covered by the set
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(3, 5));
+    /// let holes = set.holes_within(&AtomicInterval::closed(1, 10));
+    ///
+    /// assert_eq!(holes.intervals, vec![
+    ///     AtomicInterval::closed_open(1, 3),
+    ///     AtomicInterval::open_closed(5, 10),
+    /// ]);
+    /// ```
+    pub fn holes_within(&self, window: &AtomicInterval<T>) -> Self {
+        self.complement_within(window)
+    }
+
+    /// Walks the span of the interval set from start to end, yielding its covered atomic
+    /// intervals and the gaps between them in order.
+    ///
+    /// The set is normalized first, so covered and gap segments always alternate,
+    /// starting and ending with a covered segment.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the `Segment`s of the span, empty if the set is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::{AtomicInterval, IntervalSet, Segment};
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+    ///     .union(&IntervalSet::from(AtomicInterval::closed(6, 8)));
+    ///
+    /// assert_eq!(set.segments().collect::<Vec<_>>(), vec![
+    ///     Segment::Covered(AtomicInterval::closed(1, 3)),
+    ///     Segment::Gap(AtomicInterval::open(3, 6)),
+    ///     Segment::Covered(AtomicInterval::closed(6, 8)),
+    /// ]);
+    /// ```
+    pub fn segments(&self) -> impl Iterator<Item = Segment<T>> {
+        let intervals = self.normalized().into_owned().intervals;
+
+        let mut segments = Vec::with_capacity(2 * intervals.len());
+        let mut previous: Option<&AtomicInterval<T>> = None;
+        for interval in &intervals {
+            if let Some(previous) = previous {
+                // The gap starts where the previous interval ends and ends where the next one starts.
+                let left = match previous.right() {
+                    Bound::Included(value) => Bound::Excluded(value.clone()),
+                    Bound::Excluded(value) => Bound::Included(value.clone()),
+                };
+                let right = match interval.left() {
+                    Bound::Included(value) => Bound::Excluded(value.clone()),
+                    Bound::Excluded(value) => Bound::Included(value.clone()),
+                };
+                if let Ok(gap) = AtomicInterval::new(left, right) {
+                    segments.push(Segment::Gap(gap));
+                }
+            }
+            segments.push(Segment::Covered(interval.clone()));
+            previous = Some(interval);
+        }
+
+        segments.into_iter()
+    }
+
+    /// Computes how many of the given atomic intervals cover each region.
+    ///
+    /// Sweeps over all endpoints and returns the contiguous sub-intervals covered by at least one
+    /// input, annotated with the number of inputs covering them. A new sub-interval starts wherever
+    /// an input starts or ends, so adjacent inputs produce separate sub-intervals even at equal depth.
+    /// Inputs with a bound that cannot be compared, such as `NaN`, cover no region and are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `atoms` - The atomic intervals, which may overlap
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of sub-intervals sorted by left bound, each with its coverage depth
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let bookings = vec![AtomicInterval::closed_open(9, 12), AtomicInterval::closed_open(11, 13)];
+    /// let depth = IntervalSet::coverage_depth(&bookings);
+    ///
+    /// assert_eq!(depth, vec![
+    ///     (AtomicInterval::closed_open(9, 11), 1),
+    ///     (AtomicInterval::closed_open(11, 12), 2),
+    ///     (AtomicInterval::closed_open(12, 13), 1),
+    /// ]);
+    /// ```
+    pub fn coverage_depth(atoms: &[AtomicInterval<T>]) -> Vec<(AtomicInterval<T>, usize)> {
+        // Each endpoint changes the depth either at its value or right after it: (value, at value, after value).
+        let mut events: Vec<(T, isize, isize)> = Vec::with_capacity(2 * atoms.len());
+        for atom in atoms {
+            let (left, right) = (atom.left().value(), atom.right().value());
+            if left.partial_cmp(left).is_none() || right.partial_cmp(right).is_none() {
+                continue;
+            }
+            match atom.left() {
+                Bound::Included(value) => events.push((value.clone(), 1, 0)),
+                Bound::Excluded(value) => events.push((value.clone(), 0, 1)),
+            }
+            match atom.right() {
+                Bound::Included(value) => events.push((value.clone(), 0, -1)),
+                Bound::Excluded(value) => events.push((value.clone(), -1, 0)),
+            }
+        }
+        events.sort_by(|a, b| cmp_total(&a.0, &b.0));
+
+        // Split the line into elementary pieces: the endpoints and the open gaps between them.
+        // Each piece records its depth and whether an input starts or ends right before it.
+        let mut pieces: Vec<(Bound<T>, Bound<T>, isize, bool)> = Vec::with_capacity(2 * events.len());
+        let mut depth = 0;
+        let mut previous: Option<(T, bool)> = None;
+        let mut index = 0;
+        while index < events.len() {
+            let value = events[index].0.clone();
+            let (mut at_value, mut after_value) = (0, 0);
+            let (mut changed_at_value, mut changed_after_value) = (false, false);
+            while index < events.len() && cmp_total(&events[index].0, &value) == Ordering::Equal {
+                at_value += events[index].1;
+                after_value += events[index].2;
+                changed_at_value |= events[index].1 != 0;
+                changed_after_value |= events[index].2 != 0;
+                index += 1;
+            }
+
+            if let Some((previous_value, changed)) = previous.take() {
+                pieces.push((Bound::Excluded(previous_value), Bound::Excluded(value.clone()), depth, changed));
+            }
+            depth += at_value;
+            pieces.push((Bound::Included(value.clone()), Bound::Included(value.clone()), depth, changed_at_value));
+            depth += after_value;
+            previous = Some((value, changed_after_value));
+        }
+
+        // Merge consecutive pieces into runs, starting a new run wherever an input starts or ends.
+        let mut result = Vec::new();
+        let mut run: Option<(Bound<T>, Bound<T>, isize)> = None;
+        for (left, right, depth, changed) in pieces {
+            match &mut run {
+                Some((_, end, _)) if !changed => *end = right,
+                _ => {
+                    if let Some((start, end, depth)) = run.take() {
+                        result.push((AtomicInterval::new(start, end).unwrap(), depth as usize));
+                    }
+                    if depth > 0 {
+                        run = Some((left, right, depth));
+                    }
+                }
+            }
+        }
+        if let Some((start, end, depth)) = run {
+            result.push((AtomicInterval::new(start, end).unwrap(), depth as usize));
+        }
+
+        result
+    }
+
+    /// Counts the intervals of the set containing a value.
+    ///
+    /// This is only meaningful on a set built from raw, possibly overlapping intervals, and must be
+    /// called before normalizing it: a normalized set never has more than one interval containing a value.
+    /// See [`IntervalSet::coverage_depth`] for the depth over the whole set.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to look for
+    ///
+    /// # Returns
+    ///
+    /// The number of intervals of the set containing `value`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let bookings = IntervalSet {
+    ///     intervals: vec![AtomicInterval::closed_open(9, 12), AtomicInterval::closed_open(11, 13)],
+    /// };
+    /// assert_eq!(bookings.depth_at(&11), 2);
+    /// assert_eq!(bookings.depth_at(&12), 1);
+    /// ```
+    pub fn depth_at(&self, value: &T) -> usize {
+        self.intervals.iter().filter(|interval| interval.contains(value)).count()
+    }
+}
+
+impl<T: PartialOrd + Clone> Not for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    /// Computes the complement of the interval set with the `!` operator.
+    ///
+    /// As intervals cannot be unbounded, the complement is taken within the span of the set,
+    /// i.e. it yields the gaps between the intervals of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+    ///     .union(&IntervalSet::from(AtomicInterval::closed(6, 8)));
+    /// assert_eq!((!&set).intervals, vec![AtomicInterval::open(3, 6)]);
+    /// ```
+    fn not(self) -> IntervalSet<T> {
+        match self.span() {
+            Some(span) => self.complement_within(&span),
+            None => IntervalSet::new(),
+        }
+    }
+}
+
+impl<T: PartialOrd + Clone> Not for IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    /// Computes the complement of an owned interval set within its span with the `!` operator.
+    fn not(self) -> IntervalSet<T> {
+        !&self
+    }
+}
+
+impl<T: PartialOrd + Clone> BitOr<&IntervalSet<T>> for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    /// Computes the union of two interval sets with the `|` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let a = IntervalSet::from(AtomicInterval::closed(1, 5));
+    /// let b = IntervalSet::from(AtomicInterval::closed(3, 7));
+    /// assert_eq!(&a | &b, a.union(&b));
+    /// ```
+    fn bitor(self, rhs: &IntervalSet<T>) -> IntervalSet<T> {
+        self.union(rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> BitOr for IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    /// Computes the union of two owned interval sets with the `|` operator.
+    fn bitor(self, rhs: IntervalSet<T>) -> IntervalSet<T> {
+        self.union(&rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> BitAnd<&IntervalSet<T>> for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    /// Computes the intersection of two interval sets with the `&` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let a = IntervalSet::from(AtomicInterval::closed(1, 5));
+    /// let b = IntervalSet::from(AtomicInterval::closed(3, 7));
+    /// assert_eq!(&a & &b, a.intersection(&b));
+    /// ```
+    fn bitand(self, rhs: &IntervalSet<T>) -> IntervalSet<T> {
+        self.intersection(rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> BitAnd for IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    /// Computes the intersection of two owned interval sets with the `&` operator.
+    fn bitand(self, rhs: IntervalSet<T>) -> IntervalSet<T> {
+        self.intersection(&rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> Sub<&IntervalSet<T>> for &IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    /// Computes the difference of two interval sets with the `-` operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let a = IntervalSet::from(AtomicInterval::closed(1, 5));
+    /// let b = IntervalSet::from(AtomicInterval::closed(3, 7));
+    /// assert_eq!(&a - &b, a.difference(&b));
+    /// ```
+    fn sub(self, rhs: &IntervalSet<T>) -> IntervalSet<T> {
+        self.difference(rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> Sub for IntervalSet<T> {
+    type Output = IntervalSet<T>;
+
+    /// Computes the difference of two owned interval sets with the `-` operator.
+    fn sub(self, rhs: IntervalSet<T>) -> IntervalSet<T> {
+        self.difference(&rhs)
+    }
+}
+
+impl<T: PartialOrd + Clone> PartialOrd for IntervalSet<T> {
+    /// Compares two interval sets by the subset relation, so that `a <= b` means that `a` is a subset of `b`.
+    ///
+    /// This is a partial order, not a total one: two sets where neither contains the other,
+    /// such as two overlapping or disjoint sets, are incomparable. To stay consistent with the derived
+    /// `PartialEq`, two sets holding the same points only compare as equal if they also hold the same
+    /// intervals; otherwise, such as for an unnormalized set and its normalized form, they are incomparable.
+    /// Use [`IntervalSet::equals_pointset`] to compare the points alone.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Ordering::Less)` if `self` is a proper subset of `other`
+    /// * `Some(Ordering::Equal)` if both sets are equal with `==`
+    /// * `Some(Ordering::Greater)` if `self` is a proper superset of `other`
+    /// * `None` if neither set contains the other, or if both hold the same points as different intervals
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let a = IntervalSet::from(AtomicInterval::closed(2, 3));
+    /// let b = IntervalSet::from(AtomicInterval::closed(1, 5));
+    /// let c = IntervalSet::from(AtomicInterval::closed(4, 8));
+    ///
+    /// assert!(a < b);
+    /// assert!(b >= a);
+    /// assert_eq!(b.partial_cmp(&c), None);
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let is_subset = self.difference(other).is_empty();
+        let is_superset = other.difference(self).is_empty();
+        match (is_subset, is_superset) {
+            (true, true) if self == other => Some(Ordering::Equal),
+            (true, true) => None,
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+/// A trait implementation for `IntervalSet<T>` where `T` is a numeric type.
+/// Provides methods for resizing and sampling interval sets.
+impl<T> IntervalSet<T>
+where
+    T: PartialOrd + Clone + Add<Output = T> + Sub<Output = T>,
+{
+    /// Expands every interval of the set by a margin on both sides.
+    ///
+    /// Expanding may cause previously disjoint intervals to overlap, so the result is normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin` - The amount to expand each side of each interval by, which must not be negative
+    ///
+    /// # Returns
+    ///
+    /// A new normalized `IntervalSet<T>` with every interval widened by `margin`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+    ///     .union(&IntervalSet::from(AtomicInterval::closed(5, 8)));
+    /// assert_eq!(set.expand(1).intervals, vec![AtomicInterval::closed(0, 9)]);
+    /// ```
+    pub fn expand(&self, margin: T) -> Self {
+        let intervals = self.intervals.iter().map(|interval| interval.expand(margin.clone())).collect();
+        IntervalSet { intervals }.normalize()
+    }
+
+    /// Shrinks every interval of the set by a margin on both sides.
+    ///
+    /// Intervals that would collapse are removed from the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin` - The amount to shrink each side of each interval by
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalSet<T>` with every interval narrowed by `margin`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+    ///     .union(&IntervalSet::from(AtomicInterval::closed(5, 10)));
+    /// assert_eq!(set.shrink(2).intervals, vec![AtomicInterval::closed(7, 8)]);
+    /// ```
+    pub fn shrink(&self, margin: T) -> Self {
+        let intervals = self.intervals.iter().filter_map(|interval| interval.shrink(margin.clone())).collect();
+        IntervalSet { intervals }
+    }
+
+    /// Coalesces the intervals of the set that are separated by small gaps.
+    ///
+    /// Consecutive intervals whose gap, measured between the values of their bounds, is at most
+    /// `max_gap` are merged into a single interval spanning both, gap included.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_gap` - The widest gap to bridge, which must not be negative
+    ///
+    /// # Returns
+    ///
+    /// A new `IntervalSet<T>` with the small gaps bridged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(1, 5))
+    ///     .union(&IntervalSet::from(AtomicInterval::closed(6, 9)))
+    ///     .union(&IntervalSet::from(AtomicInterval::closed(12, 15)));
+    /// assert_eq!(set.coalesce(1).intervals, vec![AtomicInterval::closed(1, 9), AtomicInterval::closed(12, 15)]);
+    /// ```
+    pub fn coalesce(&self, max_gap: T) -> Self {
+        if max_gap < max_gap.clone() - max_gap.clone() {
+            panic!("The following condition must be valid: `max_gap >= 0`");
+        }
+
+        let mut intervals: Vec<AtomicInterval<T>> = Vec::new();
+        for interval in &self.normalized().intervals {
+            if let Some(last) = intervals.last_mut() {
+                let gap = interval.left().value().clone() - last.right().value().clone();
+                if gap <= max_gap {
+                    *last = AtomicInterval::new(last.left().clone(), interval.right().clone())
+                        .expect("Sorted intervals cannot produce invalid bounds");
+                    continue;
+                }
+            }
+            intervals.push(interval.clone());
+        }
+        IntervalSet { intervals }
+    }
+
+    /// Splits the set into clusters wherever the gap between consecutive intervals exceeds `max_gap`.
+    ///
+    /// Gaps are measured between the values of the bounds, as in [`IntervalSet::coalesce`], but the
+    /// intervals are kept apart rather than merged: each cluster holds the original intervals.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_gap` - The widest gap allowed within a cluster, which must not be negative
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of normalized `IntervalSet<T>` in ascending order, empty if the set is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(vec![AtomicInterval::closed(1, 3), AtomicInterval::closed(20, 22)]);
+    /// let clusters = set.cluster(5);
+    ///
+    /// assert_eq!(clusters.len(), 2);
+    /// assert_eq!(clusters[1].intervals, vec![AtomicInterval::closed(20, 22)]);
+    /// ```
+    pub fn cluster(&self, max_gap: T) -> Vec<Self> {
+        if max_gap < max_gap.clone() - max_gap.clone() {
+            panic!("The following condition must be valid: `max_gap >= 0`");
+        }
+
+        let mut clusters: Vec<IntervalSet<T>> = Vec::new();
+        for interval in &self.normalized().intervals {
+            if let Some(last) = clusters.last().and_then(|cluster| cluster.intervals.last()) {
+                let gap = interval.left().value().clone() - last.right().value().clone();
+                if gap <= max_gap {
+                    clusters.last_mut().unwrap().intervals.push(interval.clone());
+                    continue;
+                }
+            }
+            clusters.push(IntervalSet::from(interval.clone()));
+        }
+        clusters
+    }
+
+    /// Samples points at a fixed step within the intervals of the set.
+    ///
+    /// Sampling restarts at the left bound of each interval, skipping the gaps between them.
+    /// An excluded left bound is not sampled, so sampling of that interval starts one step after it.
+    /// Sampling of an interval stops before a step would pass its right bound, so it never overflows
+    /// near the limits of `T`, or when the step is too small to change a floating-point sample.
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - The spacing between samples, which must be positive
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of the sampled points, in ascending order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(0, 5))
+    ///     .union(&IntervalSet::from(AtomicInterval::closed(10, 12)));
+    /// assert_eq!(set.sample_every(2), vec![0, 2, 4, 10, 12]);
+    /// ```
+    pub fn sample_every(&self, step: T) -> Vec<T> {
+        if step <= step.clone() - step.clone() {
+            panic!("The following condition must be valid: `step > 0`");
+        }
+
+        let zero = step.clone() - step.clone();
+        let mut samples = Vec::new();
+        for interval in &self.intervals {
+            let right = interval.right().value();
+            let mut sample = interval.left().value().clone();
+            if interval.contains(&sample) {
+                samples.push(sample.clone());
+            }
+            loop {
+                // Checks that `sample + step` does not pass the right bound, without computing a value that could overflow.
+                let fits = if sample >= zero {
+                    right.clone() - sample.clone() >= step
+                } else {
+                    sample.clone() + step.clone() <= *right
+                };
+                if !fits {
+                    break;
+                }
+                let next = sample.clone() + step.clone();
+                if next <= sample || !interval.contains(&next) {
+                    break;
+                }
+                samples.push(next.clone());
+                sample = next;
+            }
+        }
+        samples
+    }
+}
+
+/// A trait implementation for `IntervalSet<T>` where `T` is a numeric type.
+/// Provides methods for building interval sets over a cyclic domain.
+impl<T> IntervalSet<T>
+where
+    T: PartialOrd + Clone + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+{
+    /// Creates an interval set over the cyclic domain `[0, modulus)`, such as the seconds of a day.
+    ///
+    /// Every interval is first shifted by a multiple of `modulus` so that its left bound lies within
+    /// the domain. An interval reaching past `modulus` then wraps around, and is split into a piece
+    /// ending at `modulus` and a piece starting at `0`. An interval longer than `modulus` covers the
+    /// whole domain. The resulting set is normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `atoms` - The intervals of the set, which may lie anywhere
+    /// * `modulus` - The length of the cycle, which must be positive
+    ///
+    /// # Returns
+    ///
+    /// A new normalized `IntervalSet<T>` within `[0, modulus)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let night_shift = IntervalSet::wrapping(vec![AtomicInterval::closed_open(80000, 90000)], 86400);
+    /// assert_eq!(night_shift.intervals, vec![
+    ///     AtomicInterval::closed_open(0, 3600),
+    ///     AtomicInterval::closed_open(80000, 86400),
+    /// ]);
+    /// ```
+    pub fn wrapping(atoms: Vec<AtomicInterval<T>>, modulus: T) -> Self {
+        let zero = modulus.clone() - modulus.clone();
+        if modulus <= zero {
+            panic!("The following condition must be valid: `modulus > 0`");
+        }
+        let domain = AtomicInterval::closed_open(zero.clone(), modulus.clone());
+
+        let mut intervals = Vec::with_capacity(atoms.len());
+        for atom in atoms {
+            if atom.length() > modulus {
+                intervals.push(domain.clone());
+                continue;
+            }
+
+            // Shift the interval so that its left bound lies within the domain, also for negative values.
+            let left = atom.left().value().clone();
+            let offset = ((left.clone() % modulus.clone()) + modulus.clone()) % modulus.clone();
+            let shift = left - offset;
+            let (left, right) = atom.into_bounds();
+            let left = left.map(|value| value - shift.clone());
+            let right = right.map(|value| value - shift.clone());
+
+            let wraps = match &right {
+                Bound::Included(value) => *value >= modulus,
+                Bound::Excluded(value) => *value > modulus,
+            };
+            if wraps {
+                intervals.push(AtomicInterval::new(left, Bound::Excluded(modulus.clone())).expect("The left bound lies within the domain"));
+                let right = right.map(|value| value - modulus.clone());
+                intervals.extend(AtomicInterval::new(Bound::Included(zero.clone()), right).ok());
+            } else {
+                intervals.push(AtomicInterval::new(left, right).expect("Shifting preserves valid bounds"));
+            }
+        }
+        IntervalSet::from(intervals)
+    }
+}
+
+/// A trait implementation for `IntervalSet<T>` where `T` is a numeric type.
+/// Provides methods for measuring interval sets.
+impl<T> IntervalSet<T>
+where
+    T: PartialOrd + Clone + Sub,
+    T::Output: Sum,
+{
+    /// Computes the total length covered by the interval set.
+    ///
+    /// The set is assumed to be normalized, so that no length is counted twice.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the lengths of the intervals of the set, zero if the set is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+    ///     .union(&IntervalSet::from(AtomicInterval::open(5, 10)));
+    /// assert_eq!(set.total_length(), 7);
+    /// ```
+    pub fn total_length(&self) -> T::Output {
+        self.intervals.iter().map(|interval| interval.length()).sum()
+    }
+
+    /// Computes the length of the interval set that is covered by another interval set.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The interval set covering the current one
+    ///
+    /// # Returns
+    ///
+    /// The total length of the intersection of both sets
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let a = IntervalSet::from(AtomicInterval::closed(0, 10));
+    /// let b = IntervalSet::from(AtomicInterval::closed(6, 20));
+    /// assert_eq!(a.covered_length(&b), 4);
+    /// ```
+    pub fn covered_length(&self, other: &Self) -> T::Output {
+        self.intersection(other).total_length()
+    }
+}
+
+/// A trait implementation for `IntervalSet<T>` where `T` is a numeric type with lengths convertible to `f64`.
+/// Provides methods for comparing interval sets.
+impl<T> IntervalSet<T>
+where
+    T: PartialOrd + Clone + Sub,
+    T::Output: Sum + AsF64,
+{
+    /// Computes the Jaccard similarity between the interval set and another interval set.
+    ///
+    /// The similarity is the total length of the intersection divided by the total length
+    /// of the union, ranging from `0.0` for disjoint sets to `1.0` for equal sets.
+    /// Two empty sets, as well as two sets whose union has zero length, have a similarity of `1.0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other interval set to compare with
+    ///
+    /// # Returns
+    ///
+    /// The Jaccard similarity of both sets, between `0.0` and `1.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let a = IntervalSet::from(AtomicInterval::closed(0, 4));
+    /// let b = IntervalSet::from(AtomicInterval::closed(2, 6));
+    /// assert_eq!(a.jaccard(&b), 2.0 / 6.0);
+    /// ```
+    pub fn jaccard(&self, other: &Self) -> f64 {
+        let union = self.union(other).total_length().as_f64();
+        if union == 0.0 {
+            return 1.0;
+        }
+        let intersection = self.intersection(other).total_length().as_f64();
+        intersection / union
+    }
+
+    /// Computes the fraction of the interval set that is covered by another interval set.
+    ///
+    /// An interval set with zero length, such as an empty set, is considered fully covered.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The interval set covering the current one
+    ///
+    /// # Returns
+    ///
+    /// The covered length divided by the total length of the current set, between `0.0` and `1.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let a = IntervalSet::from(AtomicInterval::closed(0, 10));
+    /// let b = IntervalSet::from(AtomicInterval::closed(6, 20));
+    /// assert_eq!(a.coverage_ratio(&b), 0.4);
+    /// ```
+    pub fn coverage_ratio(&self, other: &Self) -> f64 {
+        let total = self.total_length().as_f64();
+        if total == 0.0 {
+            return 1.0;
+        }
+        let covered = self.covered_length(other).as_f64();
+        covered / total
+    }
+}
+
+/// A trait implementation for `IntervalSet<T>` where `T` is a numeric type.
+/// Provides methods for dividing interval sets.
+impl<T> IntervalSet<T>
+where
+    T: PartialOrd + Clone + Add<Output = T> + Sub<Output = T> + Div<Output = T> + TryFrom<usize>,
+{
+    /// Divides the span of the interval set into `n` contiguous sub-intervals of equal width.
+    ///
+    /// Every sub-interval but the last one is left-closed and right-open, so that they do not
+    /// overlap. The first and last sub-intervals keep the inclusivity of the span's bounds.
+    /// When the width does not divide evenly (e.g. for integers), the last sub-interval absorbs
+    /// the remainder, and if the span is too narrow for `n` non-empty pieces fewer are returned.
+    /// If `n` is not representable in `T`, the span is split as with [`AtomicInterval::split_into`].
+    /// Interval sets over `f64` are divided with [`IntervalSet::partition_span_f64`].
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of sub-intervals
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of contiguous `AtomicInterval<T>` covering the span, empty if `n == 0` or the set is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(AtomicInterval::closed(0, 10));
+    /// let chunks = set.partition_span(3);
+    ///
+    /// assert_eq!(chunks, vec![
+    ///     AtomicInterval::closed_open(0, 3),
+    ///     AtomicInterval::closed_open(3, 6),
+    ///     AtomicInterval::closed(6, 10),
+    /// ]);
+    /// ```
+    pub fn partition_span(&self, n: usize) -> Vec<AtomicInterval<T>> {
+        match self.span() {
+            Some(span) => span.split_into(n),
+            None => vec![],
+        }
+    }
+}
+
+/// A trait implementation for `IntervalSet<f64>`.
+/// Provides methods for dividing floating-point interval sets.
+impl IntervalSet<f64> {
+    /// Divides the span of the interval set into `n` contiguous sub-intervals of equal width,
+    /// like [`IntervalSet::partition_span`] does for types implementing `TryFrom<usize>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of sub-intervals
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of contiguous `AtomicInterval<f64>` covering the span, empty if `n == 0` or the set is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(vec![AtomicInterval::closed(0.0, 1.0), AtomicInterval::closed(2.0, 3.0)]);
+    /// assert_eq!(set.partition_span_f64(2), vec![
+    ///     AtomicInterval::closed_open(0.0, 1.5),
+    ///     AtomicInterval::closed(1.5, 3.0),
+    /// ]);
+    /// ```
+    pub fn partition_span_f64(&self, n: usize) -> Vec<AtomicInterval<f64>> {
+        match self.span() {
+            Some(span) => span.split_into_f64(n),
+            None => vec![],
+        }
+    }
+}
+
+/// A trait implementation for `IntervalSet<T>` where `T` is a discrete type, such as an integer.
+/// Provides methods for building interval sets from discrete values.
+impl<T> IntervalSet<T>
+where
+    T: PartialOrd + Clone + Discrete,
+{
+    /// Creates an interval set covering the given values, merging runs of consecutive values.
+    ///
+    /// Each value becomes a point interval, and values that follow each other, like `3` and `4`,
+    /// are coalesced into a single closed interval. The values may be unsorted and repeated.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The values to cover
+    ///
+    /// # Returns
+    ///
+    /// A normalized `IntervalSet<T>` of closed and point intervals covering exactly the given values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from_points([3, 4, 5, 7]);
+    /// assert_eq!(set.intervals, vec![AtomicInterval::closed(3, 5), AtomicInterval::point(7)]);
+    /// ```
+    pub fn from_points(points: impl IntoIterator<Item = T>) -> Self {
+        let mut points: Vec<T> = points.into_iter().collect();
+        points.sort_by(cmp_total);
+
+        // Each run is kept as its first and last value.
+        let mut runs: Vec<(T, T)> = Vec::new();
+        for point in points {
+            if let Some((_, last)) = runs.last_mut() {
+                if point <= *last {
+                    continue;
+                }
+                if last.successor().as_ref() == Some(&point) {
+                    *last = point;
+                    continue;
+                }
+            }
+            runs.push((point.clone(), point));
+        }
+
+        let intervals = runs
+            .into_iter()
+            .map(|(first, last)| AtomicInterval::closed_or_point(first, last).unwrap())
+            .collect();
+        IntervalSet { intervals }
+    }
+
+    /// Converts the interval set into half-open ranges, one per atomic interval in normalized form.
+    ///
+    /// Each interval is first converted to its canonical left-closed, right-open form with
+    /// [`AtomicInterval::to_half_open`], so every bound style can be expressed over discrete values.
+    /// Intervals holding no discrete value, such as `(4, 5)` over integers, are left out.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Vec<Range<T>>)` with the ranges sorted in ascending order, or `None` if an interval
+    /// includes the greatest value of `T`, which a half-open range cannot express
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timekeep_rs::AtomicInterval;
+    /// use timekeep_rs::IntervalSet;
+    ///
+    /// let set = IntervalSet::from(vec![AtomicInterval::closed(1, 3), AtomicInterval::open(5, 8)]);
+    /// assert_eq!(set.to_ranges_half_open(), Some(vec![1..4, 6..8]));
+    /// assert_eq!(IntervalSet::from(AtomicInterval::open(4, 5)).to_ranges_half_open(), Some(vec![]));
+    /// ```
+    pub fn to_ranges_half_open(&self) -> Option<Vec<Range<T>>> {
+        let mut ranges = Vec::new();
+        for interval in &self.normalized().intervals {
+            match interval.to_half_open() {
+                Some(half_open) => ranges.push(half_open.left().value().clone()..half_open.right().value().clone()),
+                // An included right bound without a successor cannot be expressed; anything else has no values.
+                None if matches!(interval.right(), Bound::Included(value) if value.successor().is_none()) => return None,
+                None => {}
+            }
+        }
+        Some(ranges)
+    }
+}
+
+/// A builder accumulating atomic intervals and normalizing them once into an `IntervalSet`.
+///
+/// Unlike repeated calls to [`IntervalSet::insert`], pushing an interval does no merging,
+/// so this is the efficient way to load a large batch of intervals.
+///
+/// # Examples
+/// ```
+/// use timekeep_rs::{AtomicInterval, IntervalSetBuilder};
+///
+/// let mut builder = IntervalSetBuilder::new();
+/// builder.push(AtomicInterval::closed(4, 7));
+/// builder.push(AtomicInterval::closed(1, 5));
+///
+/// assert_eq!(builder.build().intervals, vec![AtomicInterval::closed(1, 7)]);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct IntervalSetBuilder<T> {
+    intervals: Vec<AtomicInterval<T>>,
+}
+
+impl<T: PartialOrd + Clone> IntervalSetBuilder<T> {
+    /// Returns a builder holding no intervals.
+    pub fn new() -> Self {
+        IntervalSetBuilder { intervals: Vec::new() }
+    }
+
+    /// Returns a builder holding no intervals, with room for `capacity` intervals.
+    pub fn with_capacity(capacity: usize) -> Self {
+        IntervalSetBuilder { intervals: Vec::with_capacity(capacity) }
+    }
+
+    /// Adds an atomic interval to the builder, in any order and possibly overlapping the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The atomic interval to add
+    ///
+    /// # Returns
+    ///
+    /// The builder, so that calls can be chained
+    pub fn push(&mut self, interval: AtomicInterval<T>) -> &mut Self {
+        self.intervals.push(interval);
+        self
+    }
+
+    /// Normalizes the accumulated intervals into an interval set.
+    ///
+    /// # Returns
+    ///
+    /// A normalized `IntervalSet<T>` covering all the pushed intervals
+    pub fn build(self) -> IntervalSet<T> {
+        IntervalSet { intervals: self.intervals }.normalize()
+    }
+}
+
+impl<T: PartialOrd + Clone> Default for IntervalSetBuilder<T> {
+    /// Returns a builder holding no intervals, same as [`IntervalSetBuilder::new`].
+    fn default() -> Self {
+        IntervalSetBuilder::new()
+    }
+}
+
+impl<T: PartialOrd + Clone> Extend<AtomicInterval<T>> for IntervalSetBuilder<T> {
+    /// Adds every atomic interval of an iterator to the builder.
+    fn extend<I: IntoIterator<Item = AtomicInterval<T>>>(&mut self, iter: I) {
+        self.intervals.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_from_atomic_interval() {
+        let atomic_interval = AtomicInterval::closed(1, 5);
+        let interval_set: IntervalSet<i32> = IntervalSet::from(atomic_interval.clone());
+        assert_eq!(interval_set.intervals.len(), 1);
+        assert_eq!(interval_set.intervals[0], atomic_interval);
+    }
+
+    #[test]
+    fn test_union_between_two_overlapping_intervals() {
+        let interval1 = AtomicInterval::closed(1, 3);
+        let interval2 = AtomicInterval::closed(4, 7);
+        let interval3 = AtomicInterval::closed(2, 4);
+        let interval4 = AtomicInterval::closed(7, 8);
+        let union = IntervalSet::from(interval1).union(&IntervalSet::from(interval2));
+        let union = union.union(&IntervalSet::from(interval3));
+        let union = union.union(&IntervalSet::from(interval4));
+        assert_eq!(union.intervals.len(), 1);
+        assert_eq!(union.intervals[0], AtomicInterval::closed(1, 8));
+    }
+
+    #[test]
+    fn test_union_between_two_disjoint_intervals() {
+        let interval1 = AtomicInterval::closed(1, 3);
+        let interval2 = AtomicInterval::closed(4, 7);
+        let interval3 = AtomicInterval::closed(5, 8);
+        let union = IntervalSet::from(interval1).union(&IntervalSet::from(interval2));
+        let union = union.union(&IntervalSet::from(interval3));
+        assert_eq!(union.intervals.len(), 2);
+        assert_eq!(union.intervals[0], AtomicInterval::closed(1, 3));
+        assert_eq!(union.intervals[1], AtomicInterval::closed(4, 8));
+    }
+
+    #[test]
+    fn test_union_merges_adjacent_after_tied_left_bounds() {
+        let set1 = IntervalSet::from(AtomicInterval::open(-7, -4))
+            .union(&IntervalSet::from(AtomicInterval::open(-4, -3)));
+        let set2 = IntervalSet::from(AtomicInterval::closed_open(-4, -3));
+        let union = set1.union(&set2);
+        assert_eq!(union.intervals.len(), 1);
+        assert_eq!(union.intervals[0], AtomicInterval::open(-7, -3));
+    }
+
+    #[test]
+    fn test_intersection_between_two_overlapping_intervals() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(3, 7);
+        let interval1 = IntervalSet::from(interval1);
+        let interval2 = IntervalSet::from(interval2);
+        let intersection = interval1.intersection(&interval2);
+        assert_eq!(intersection.intervals.len(), 1);
+        assert_eq!(intersection.intervals[0], AtomicInterval::closed(3, 5));
+    }
+
+    #[test]
+    fn test_intersection_between_two_disjoint_intervals() {
+        let interval1 = AtomicInterval::closed(1, 3);
+        let interval2 = AtomicInterval::closed(4, 7);
+        let interval1 = IntervalSet::from(interval1);
+        let interval2 = IntervalSet::from(interval2);
+        let intersection = interval1.intersection(&interval2);
+        assert!(intersection.is_empty());
+    }
+
+    #[test]
+    fn test_difference_between_two_overlapping_intervals() {
+        let interval1 = AtomicInterval::closed(1, 5);
+        let interval2 = AtomicInterval::closed(3, 7);
+        let interval1 = IntervalSet::from(interval1);
+        let interval2 = IntervalSet::from(interval2);
+        let difference = interval1.difference(&interval2);
+        assert_eq!(difference.intervals.len(), 1);
+        assert_eq!(difference.intervals[0], AtomicInterval::closed_open(1, 3));
+    }
+
+    #[test]
+    fn test_difference_between_two_disjoint_intervals() {
+        let interval1 = AtomicInterval::closed(1, 3);
+        let interval2 = AtomicInterval::closed(4, 7);
+        let interval1 = IntervalSet::from(interval1);
+        let interval2 = IntervalSet::from(interval2);
+        let difference = interval1.difference(&interval2);
+        assert_eq!(difference.intervals.len(), 1);
+        assert_eq!(difference.intervals[0], AtomicInterval::closed(1, 3));
+    }
+
+    #[test]
+    fn test_span() {
+        let set = IntervalSet::from(AtomicInterval::open(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::closed(6, 8)));
+        assert_eq!(set.span(), Some(AtomicInterval::open_closed(1, 8)));
+        assert_eq!(IntervalSet::<i32>::new().span(), None);
+    }
+
+    #[test]
+    fn test_partition_span_into_three_chunks() {
+        let set = IntervalSet::from(AtomicInterval::closed(0, 10));
+        let chunks = set.partition_span(3);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0], AtomicInterval::closed_open(0, 3));
+        assert_eq!(chunks[1], AtomicInterval::closed_open(3, 6));
+        assert_eq!(chunks[2], AtomicInterval::closed(6, 10));
+    }
+
+    #[test]
+    fn test_partition_span_covers_gaps() {
+        let set = IntervalSet::from(AtomicInterval::open(0, 2))
+            .union(&IntervalSet::from(AtomicInterval::open(6, 8)));
+        let chunks = set.partition_span(2);
+        assert_eq!(chunks, vec![AtomicInterval::open(0, 4), AtomicInterval::closed_open(4, 8)]);
+    }
+
+    #[test]
+    fn test_partition_span_with_zero_chunks() {
+        let set = IntervalSet::from(AtomicInterval::closed(0, 10));
+        assert!(set.partition_span(0).is_empty());
+    }
+
+    #[test]
+    fn test_partition_span_of_empty_set() {
+        let set = IntervalSet::<i32>::new();
+        assert!(set.partition_span(3).is_empty());
+    }
+
+    #[test]
+    fn test_partition_span_narrower_than_chunks() {
+        let set = IntervalSet::from(AtomicInterval::closed(0, 2));
+        let chunks = set.partition_span(5);
+        assert_eq!(chunks, vec![AtomicInterval::closed(0, 2)]);
+    }
+
+    #[test]
+    fn test_partition_span_with_unrepresentable_count() {
+        let set = IntervalSet::from(AtomicInterval::closed(250u8, 255));
+        assert_eq!(set.partition_span(300).len(), 5);
+        let set = IntervalSet::from(AtomicInterval::closed(-10i8, 10));
+        assert_eq!(set.partition_span(4).len(), 4);
+    }
+
+    #[test]
+    fn test_partition_span_of_floats() {
+        let set = IntervalSet::from(vec![AtomicInterval::closed(0.0, 1.0), AtomicInterval::closed(3.0, 4.0)]);
+        assert_eq!(set.partition_span_f64(2), vec![AtomicInterval::closed_open(0.0, 2.0), AtomicInterval::closed(2.0, 4.0)]);
+    }
+
+    #[test]
+    fn test_bitor_operator_matches_union() {
+        let set1 = IntervalSet::from(AtomicInterval::closed(1, 3));
+        let set2 = IntervalSet::from(AtomicInterval::closed(2, 7));
+        assert_eq!(&set1 | &set2, set1.union(&set2));
+        assert_eq!(set1.clone() | set2.clone(), set1.union(&set2));
+    }
+
+    #[test]
+    fn test_bitand_operator_matches_intersection() {
+        let set1 = IntervalSet::from(AtomicInterval::closed(1, 5));
+        let set2 = IntervalSet::from(AtomicInterval::closed(3, 7));
+        assert_eq!(&set1 & &set2, set1.intersection(&set2));
+        assert_eq!(set1.clone() & set2.clone(), set1.intersection(&set2));
+    }
+
+    #[test]
+    fn test_sub_operator_matches_difference() {
+        let set1 = IntervalSet::from(AtomicInterval::closed(1, 5));
+        let set2 = IntervalSet::from(AtomicInterval::closed(3, 7));
+        assert_eq!(&set1 - &set2, set1.difference(&set2));
+        assert_eq!(set1.clone() - set2.clone(), set1.difference(&set2));
+    }
+
+    #[test]
+    fn test_complement_within() {
+        let set = IntervalSet::from(AtomicInterval::closed(3, 5));
+        let complement = set.complement_within(&AtomicInterval::closed(1, 10));
+        assert_eq!(complement.intervals.len(), 2);
+        assert_eq!(complement.intervals[0], AtomicInterval::closed_open(1, 3));
+        assert_eq!(complement.intervals[1], AtomicInterval::open_closed(5, 10));
+    }
+
+    #[test]
+    fn test_complement_within_smaller_universe() {
+        let set = IntervalSet::from(AtomicInterval::closed(3, 5));
+        let complement = set.complement_within(&AtomicInterval::closed(4, 10));
+        assert_eq!(complement.intervals, vec![AtomicInterval::open_closed(5, 10)]);
+    }
+
+    #[test]
+    fn test_not_operator_complements_within_span() {
+        let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::open(6, 8)))
+            .union(&IntervalSet::from(AtomicInterval::closed(10, 12)));
+        let complement = !&set;
+        assert_eq!(complement.intervals.len(), 2);
+        assert_eq!(complement.intervals[0], AtomicInterval::open_closed(3, 6));
+        assert_eq!(complement.intervals[1], AtomicInterval::closed_open(8, 10));
+        assert_eq!(!set, complement);
+    }
+
+    #[test]
+    fn test_not_operator_on_empty_and_single_interval_sets() {
+        assert!((!IntervalSet::<i32>::new()).is_empty());
+        assert!((!IntervalSet::from(AtomicInterval::closed(1, 3))).is_empty());
+    }
+
+    #[test]
+    fn test_retain_by_length() {
+        let mut set = IntervalSet::from(AtomicInterval::closed(1, 2))
+            .union(&IntervalSet::from(AtomicInterval::closed(4, 7)))
+            .union(&IntervalSet::from(AtomicInterval::point(9)))
+            .union(&IntervalSet::from(AtomicInterval::open(11, 13)));
+        set.retain(|interval| interval.length() >= 2);
+        assert_eq!(set.intervals.len(), 2);
+        assert_eq!(set.intervals[0], AtomicInterval::closed(4, 7));
+        assert_eq!(set.intervals[1], AtomicInterval::open(11, 13));
+    }
+
+    #[test]
+    fn test_coverage_depth_of_three_overlapping_intervals() {
+        let atoms = vec![
+            AtomicInterval::closed(1, 10),
+            AtomicInterval::closed(3, 8),
+            AtomicInterval::closed(5, 6),
+        ];
+        let depth = IntervalSet::coverage_depth(&atoms);
+        assert_eq!(depth, vec![
+            (AtomicInterval::closed_open(1, 3), 1),
+            (AtomicInterval::closed_open(3, 5), 2),
+            (AtomicInterval::closed(5, 6), 3),
+            (AtomicInterval::open_closed(6, 8), 2),
+            (AtomicInterval::open_closed(8, 10), 1),
+        ]);
+    }
+
+    #[test]
+    fn test_coverage_depth_of_adjacent_intervals() {
+        let atoms = vec![AtomicInterval::closed_open(1, 3), AtomicInterval::closed(3, 5)];
+        let depth = IntervalSet::coverage_depth(&atoms);
+        assert_eq!(depth, vec![
+            (AtomicInterval::closed_open(1, 3), 1),
+            (AtomicInterval::closed(3, 5), 1),
+        ]);
+    }
+
+    #[test]
+    fn test_coverage_depth_of_intervals_sharing_an_endpoint() {
+        let atoms = vec![AtomicInterval::closed(1, 3), AtomicInterval::closed(3, 5), AtomicInterval::open(7, 9)];
+        let depth = IntervalSet::coverage_depth(&atoms);
+        assert_eq!(depth, vec![
+            (AtomicInterval::closed_open(1, 3), 1),
+            (AtomicInterval::point(3), 2),
+            (AtomicInterval::open_closed(3, 5), 1),
+            (AtomicInterval::open(7, 9), 1),
+        ]);
+    }
+
+    #[test]
+    fn test_coverage_depth_of_no_intervals() {
+        assert!(IntervalSet::<i32>::coverage_depth(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_coverage_depth_ignores_nan_bounds() {
+        let atoms = vec![
+            AtomicInterval::closed(1.0, 4.0),
+            AtomicInterval::point(f64::NAN),
+            AtomicInterval::closed(2.0, 3.0),
+            AtomicInterval::closed(f64::NAN, 7.0),
+        ];
+        let depth = IntervalSet::coverage_depth(&atoms);
+        assert_eq!(depth, vec![
+            (AtomicInterval::closed_open(1.0, 2.0), 1),
+            (AtomicInterval::closed(2.0, 3.0), 2),
+            (AtomicInterval::open_closed(3.0, 4.0), 1),
+        ]);
+    }
+
+    #[test]
+    fn test_normalize_unsorted_intervals() {
+        let set = IntervalSet {
+            intervals: vec![
+                AtomicInterval::closed(8, 9),
+                AtomicInterval::closed(4, 7),
+                AtomicInterval::closed_open(1, 4),
+            ],
+        };
+        let normalized = set.normalize();
+        assert_eq!(normalized.intervals, vec![AtomicInterval::closed(1, 7), AtomicInterval::closed(8, 9)]);
+    }
+
+    #[test]
+    fn test_expand_merges_near_intervals() {
+        let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::closed(6, 8)))
+            .union(&IntervalSet::from(AtomicInterval::closed(20, 22)));
+        let expanded = set.expand(2);
+        assert_eq!(expanded.intervals.len(), 2);
+        assert_eq!(expanded.intervals[0], AtomicInterval::closed(-1, 10));
+        assert_eq!(expanded.intervals[1], AtomicInterval::closed(18, 24));
+    }
+
+    #[test]
+    fn test_shrink_removes_collapsed_intervals() {
+        let set = IntervalSet::from(AtomicInterval::open(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::closed(6, 12)));
+        let shrunk = set.shrink(1);
+        assert_eq!(shrunk.intervals, vec![AtomicInterval::closed(7, 11)]);
+    }
+
+    #[test]
+    fn test_sample_every_skips_gaps() {
+        let set = IntervalSet::from(AtomicInterval::closed(0, 5))
+            .union(&IntervalSet::from(AtomicInterval::closed(10, 12)));
+        assert_eq!(set.sample_every(2), vec![0, 2, 4, 10, 12]);
+    }
+
+    #[test]
+    fn test_sample_every_respects_inclusivity() {
+        let set = IntervalSet::from(AtomicInterval::open(0, 6));
+        assert_eq!(set.sample_every(2), vec![2, 4]);
+        let set = IntervalSet::from(AtomicInterval::closed_open(0.0, 1.0));
+        assert_eq!(set.sample_every(0.25), vec![0.0, 0.25, 0.5, 0.75]);
+    }
+
+    #[test]
+    fn test_sample_every_at_type_limits() {
+        let set = IntervalSet::from(AtomicInterval::closed(250u8, 255));
+        assert_eq!(set.sample_every(1), vec![250, 251, 252, 253, 254, 255]);
+        assert_eq!(set.sample_every(4), vec![250, 254]);
+        let set = IntervalSet::from(AtomicInterval::open_closed(i8::MAX - 3, i8::MAX));
+        assert_eq!(set.sample_every(2), vec![i8::MAX - 1]);
+        let set = IntervalSet::from(AtomicInterval::closed(-100i8, 100));
+        assert_eq!(set.sample_every(50), vec![-100, -50, 0, 50, 100]);
+        let set = IntervalSet::from(AtomicInterval::closed(i64::MIN, i64::MIN + 2));
+        assert_eq!(set.sample_every(i64::MAX), vec![i64::MIN]);
+    }
+
+    #[test]
+    fn test_sample_every_without_progress() {
+        let set = IntervalSet::from(AtomicInterval::closed(1e17, 2e17));
+        assert_eq!(set.sample_every(1.0), vec![1e17]);
+        let set = IntervalSet::from(AtomicInterval::closed(0.0, f64::INFINITY));
+        let samples = set.sample_every(1e307);
+        assert_eq!(samples.len(), 19);
+        assert_eq!(samples.last(), Some(&f64::INFINITY));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sample_every_non_positive_step() {
+        IntervalSet::from(AtomicInterval::closed(0, 5)).sample_every(0);
+    }
+
+    #[test]
+    fn test_total_length() {
+        let set = IntervalSet::from(AtomicInterval::closed(1.0, 2.5))
+            .union(&IntervalSet::from(AtomicInterval::point(4.0)))
+            .union(&IntervalSet::from(AtomicInterval::open(5.0, 6.0)));
+        assert_eq!(set.total_length(), 2.5);
+        assert_eq!(IntervalSet::<i32>::new().total_length(), 0);
+    }
+
+    #[test]
+    fn test_jaccard_of_identical_sets() {
+        let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::closed(5, 8)));
+        assert_eq!(set.jaccard(&set), 1.0);
+        assert_eq!(IntervalSet::<i32>::new().jaccard(&IntervalSet::new()), 1.0);
+    }
+
+    #[test]
+    fn test_jaccard_of_disjoint_sets() {
+        let a = IntervalSet::from(AtomicInterval::closed(1, 3));
+        let b = IntervalSet::from(AtomicInterval::closed(5, 8));
+        assert_eq!(a.jaccard(&b), 0.0);
+        assert_eq!(a.jaccard(&IntervalSet::new()), 0.0);
+    }
+
+    #[test]
+    fn test_jaccard_of_half_overlapping_sets() {
+        let a = IntervalSet::from(AtomicInterval::closed(0.0, 2.0));
+        let b = IntervalSet::from(AtomicInterval::closed(1.0, 3.0));
+        assert_eq!(a.jaccard(&b), 1.0 / 3.0);
+        assert_eq!(b.jaccard(&a), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn test_jaccard_of_i64_timestamps() {
+        let a = IntervalSet::from(AtomicInterval::closed_open(1_700_000_000i64, 1_700_003_600));
+        let b = IntervalSet::from(AtomicInterval::closed_open(1_700_001_800i64, 1_700_005_400));
+        assert_eq!(a.jaccard(&b), 1800.0 / 5400.0);
+        assert_eq!(a.coverage_ratio(&b), 0.5);
+        let c = IntervalSet::from(AtomicInterval::closed(0u64, 10));
+        assert_eq!(c.jaccard(&IntervalSet::from(AtomicInterval::closed(5u64, 10))), 0.5);
+    }
+
+    #[test]
+    fn test_from_sorted_accepts_normalized_input() {
+        let atoms = vec![AtomicInterval::closed_open(1, 3), AtomicInterval::open(3, 5), AtomicInterval::point(7)];
+        let set = IntervalSet::from_sorted(atoms.clone()).unwrap();
+        assert_eq!(set.intervals, atoms);
+        assert_eq!(IntervalSet::<i32>::from_sorted(vec![]), Ok(IntervalSet::new()));
+    }
+
+    #[test]
+    fn test_from_sorted_rejects_overlapping_input() {
+        let atoms = vec![AtomicInterval::closed(1, 5), AtomicInterval::closed(3, 7)];
+        assert_eq!(IntervalSet::from_sorted(atoms.clone()), Err(IntervalError::Overlapping));
+        assert_eq!(IntervalSet::from_sorted_unchecked(atoms.clone()).intervals, atoms);
+    }
+
+    #[test]
+    fn test_from_sorted_rejects_adjacent_input() {
+        let atoms = vec![AtomicInterval::closed_open(1, 3), AtomicInterval::closed(3, 7)];
+        assert_eq!(IntervalSet::from_sorted(atoms), Err(IntervalError::Overlapping));
+    }
+
+    #[test]
+    fn test_from_sorted_rejects_unsorted_input() {
+        let atoms = vec![AtomicInterval::closed(5, 7), AtomicInterval::closed(1, 3)];
+        assert_eq!(IntervalSet::from_sorted(atoms), Err(IntervalError::Unsorted));
+    }
+
+    #[test]
+    fn test_iter_rev() {
+        let set = IntervalSet::from(AtomicInterval::closed(1, 2))
+            .union(&IntervalSet::from(AtomicInterval::point(4)))
+            .union(&IntervalSet::from(AtomicInterval::open(6, 9)));
+        let reversed: Vec<_> = set.iter_rev().cloned().collect();
+        assert_eq!(reversed, vec![AtomicInterval::open(6, 9), AtomicInterval::point(4), AtomicInterval::closed(1, 2)]);
+        assert_eq!(IntervalSet::<i32>::new().iter_rev().next(), None);
+    }
+
+    #[test]
+    fn test_insert_merges_two_atoms() {
+        let mut set = IntervalSet::from(AtomicInterval::closed(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::closed(5, 7)))
+            .union(&IntervalSet::from(AtomicInterval::closed(9, 10)));
+        set.insert(AtomicInterval::closed(2, 6));
+        assert_eq!(set.intervals, vec![AtomicInterval::closed(1, 7), AtomicInterval::closed(9, 10)]);
+    }
+
+    #[test]
+    fn test_insert_merges_adjacent_atoms() {
+        let mut set = IntervalSet::from(AtomicInterval::closed_open(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::open(5, 7)));
+        set.insert(AtomicInterval::closed(3, 5));
+        assert_eq!(set.intervals, vec![AtomicInterval::closed_open(1, 7)]);
+    }
+
+    #[test]
+    fn test_insert_disjoint_atom() {
+        let mut set = IntervalSet::new();
+        set.insert(AtomicInterval::closed(5, 7));
+        set.insert(AtomicInterval::closed(1, 2));
+        set.insert(AtomicInterval::open(7, 9));
+        set.insert(AtomicInterval::point(3));
+        set.insert(AtomicInterval::closed(5, 6));
+        assert_eq!(set.intervals, vec![
+            AtomicInterval::closed(1, 2),
+            AtomicInterval::point(3),
+            AtomicInterval::closed_open(5, 9),
+        ]);
+    }
+
+    #[test]
+    fn test_remove_splits_atom() {
+        let mut set = IntervalSet::from(AtomicInterval::closed(1, 10));
+        set.remove(&AtomicInterval::closed(4, 6));
+        assert_eq!(set.intervals, vec![AtomicInterval::closed_open(1, 4), AtomicInterval::open_closed(6, 10)]);
+    }
+
+    #[test]
+    fn test_remove_trims_and_drops_atoms() {
+        let mut set = IntervalSet::from(AtomicInterval::closed(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::closed(5, 7)))
+            .union(&IntervalSet::from(AtomicInterval::closed(9, 12)));
+        set.remove(&AtomicInterval::open(2, 10));
+        assert_eq!(set.intervals, vec![AtomicInterval::closed(1, 2), AtomicInterval::closed(10, 12)]);
+
+        set.remove(&AtomicInterval::closed(20, 30));
+        assert_eq!(set.intervals, vec![AtomicInterval::closed(1, 2), AtomicInterval::closed(10, 12)]);
+    }
+
+    #[test]
+    fn test_from_vec_normalizes() {
+        let set = IntervalSet::from(vec![
+            AtomicInterval::closed(8, 9),
+            AtomicInterval::open(3, 6),
+            AtomicInterval::closed(1, 4),
+            AtomicInterval::point(6),
+        ]);
+        assert_eq!(set.intervals, vec![AtomicInterval::closed(1, 6), AtomicInterval::closed(8, 9)]);
+        assert!(IntervalSet::<i32>::from(vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_from_range() {
+        assert_eq!(IntervalSet::from(1..5).intervals, vec![AtomicInterval::closed_open(1, 5)]);
+        assert_eq!(IntervalSet::from(-2.5..0.0).intervals, vec![AtomicInterval::closed_open(-2.5, 0.0)]);
+        assert!(IntervalSet::from(5..5).is_empty());
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = IntervalSet::from(5..1);
+        assert!(reversed.is_empty());
+    }
+
+    #[test]
+    fn test_from_range_inclusive() {
+        assert_eq!(IntervalSet::from(1..=5).intervals, vec![AtomicInterval::closed(1, 5)]);
+        assert_eq!(IntervalSet::from(5..=5).intervals, vec![AtomicInterval::point(5)]);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = IntervalSet::from(5..=4);
+        assert!(reversed.is_empty());
+    }
+
+    #[test]
+    fn test_is_normalized() {
+        let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::open(3, 5)))
+            .union(&IntervalSet::from(AtomicInterval::point(7)));
+        assert!(set.is_normalized());
+        assert!(IntervalSet::<i32>::new().is_normalized());
+    }
+
+    #[test]
+    fn test_is_normalized_rejects_broken_sets() {
+        let unsorted = IntervalSet { intervals: vec![AtomicInterval::closed(5, 7), AtomicInterval::closed(1, 3)] };
+        assert!(!unsorted.is_normalized());
+        let overlapping = IntervalSet { intervals: vec![AtomicInterval::closed(1, 5), AtomicInterval::closed(3, 7)] };
+        assert!(!overlapping.is_normalized());
+        let adjacent = IntervalSet { intervals: vec![AtomicInterval::closed_open(1, 3), AtomicInterval::closed(3, 7)] };
+        assert!(!adjacent.is_normalized());
+    }
+
+    #[test]
+    fn test_display_with_width() {
+        let set = IntervalSet::from(AtomicInterval::closed(1, 2))
+            .union(&IntervalSet::from(AtomicInterval::open(5, 9)));
+        assert_eq!(format!("{}", set), "[[1, 2](5, 9)]");
+        assert_eq!(format!("{:>20}", set), "      [[1, 2](5, 9)]");
+        assert_eq!(format!("{:-^18}", set), "--[[1, 2](5, 9)]--");
+    }
+
+    #[test]
+    fn test_display_with_width_and_precision() {
+        let set = IntervalSet::from(AtomicInterval::closed(1.5, 2.25))
+            .union(&IntervalSet::from(AtomicInterval::open(3.0, 4.0)));
+        assert_eq!(format!("{:.1}", set), "[[1.5, 2.2](3.0, 4.0)]");
+        assert_eq!(format!("{:>28.2}", set), "  [[1.50, 2.25](3.00, 4.00)]");
+        assert_eq!(format!("{:5.0}", set), "[[2, 2](3, 4)]");
+    }
+
+    #[test]
+    fn test_flatten_duplicate_and_nested_atoms() {
+        let set = IntervalSet {
+            intervals: vec![
+                AtomicInterval::closed(8, 9),
+                AtomicInterval::closed(1, 6),
+                AtomicInterval::open(2, 4),
+                AtomicInterval::closed(8, 9),
+                AtomicInterval::point(3),
+                AtomicInterval::closed(1, 6),
+            ],
+        };
+        let flattened = set.flatten();
+        assert_eq!(flattened.intervals, vec![AtomicInterval::closed(1, 6), AtomicInterval::closed(8, 9)]);
+        assert!(flattened.is_normalized());
+        assert_eq!(flattened.clone().flatten(), flattened);
+    }
+
+    #[test]
+    fn test_partial_cmp_of_subset() {
+        let a = IntervalSet::from(AtomicInterval::closed(2, 3))
+            .union(&IntervalSet::from(AtomicInterval::open(6, 7)));
+        let b = IntervalSet::from(AtomicInterval::closed(1, 4))
+            .union(&IntervalSet::from(AtomicInterval::closed(6, 9)));
+        assert_eq!(a.partial_cmp(&b), Some(Ordering::Less));
+        assert_eq!(b.partial_cmp(&a), Some(Ordering::Greater));
+        assert!(a < b);
+        assert!(b > a);
+        assert_eq!(IntervalSet::new().partial_cmp(&a), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn test_partial_cmp_of_equal_sets() {
+        let a = IntervalSet::from(AtomicInterval::closed(1, 4));
+        let b = IntervalSet::from(AtomicInterval::closed_open(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::closed(3, 4)));
+        assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+        assert!(a <= b);
+        assert!(a >= b);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_partial_cmp_of_unnormalized_sets() {
+        let a = IntervalSet { intervals: vec![AtomicInterval::closed(1, 3), AtomicInterval::closed(3, 5)] };
+        let b = IntervalSet::from(AtomicInterval::closed(1, 5));
+        assert!(a.equals_pointset(&b));
+        assert_ne!(a, b);
+        assert_eq!(a.partial_cmp(&b), None);
+        assert!(!a.le(&b));
+        assert!(!a.ge(&b));
+        assert_eq!(a.partial_cmp(&a.clone()), Some(Ordering::Equal));
+        let c = IntervalSet::from(AtomicInterval::closed(0, 6));
+        assert!(a < c);
+    }
+
+    #[test]
+    fn test_partial_cmp_of_incomparable_sets() {
+        let a = IntervalSet::from(AtomicInterval::closed(1, 4));
+        let b = IntervalSet::from(AtomicInterval::closed(3, 6));
+        let c = IntervalSet::from(AtomicInterval::closed(8, 9));
+        assert_eq!(a.partial_cmp(&b), None);
+        assert_eq!(a.partial_cmp(&c), None);
+        assert!(!a.le(&b));
+        assert!(!a.ge(&b));
+    }
+
+    #[test]
+    fn test_covered_length() {
+        let a = IntervalSet::from(AtomicInterval::closed(0, 4))
+            .union(&IntervalSet::from(AtomicInterval::closed(10, 14)));
+        let b = IntervalSet::from(AtomicInterval::closed(2, 12));
+        assert_eq!(a.covered_length(&b), 4);
+        assert_eq!(a.covered_length(&IntervalSet::new()), 0);
+    }
+
+    #[test]
+    fn test_coverage_ratio_of_half_covered_set() {
+        let a = IntervalSet::from(AtomicInterval::closed(0, 4))
+            .union(&IntervalSet::from(AtomicInterval::closed(10, 14)));
+        let b = IntervalSet::from(AtomicInterval::closed(2, 12));
+        assert_eq!(a.coverage_ratio(&b), 0.5);
+        assert_eq!(b.coverage_ratio(&a), 0.4);
+        assert_eq!(a.coverage_ratio(&a), 1.0);
+        assert_eq!(IntervalSet::<i32>::new().coverage_ratio(&a), 1.0);
+    }
+
+    #[test]
+    fn test_in_place_operations_match_by_value_results() {
+        let a = IntervalSet::from(AtomicInterval::closed(1, 4))
+            .union(&IntervalSet::from(AtomicInterval::open(6, 9)))
+            .union(&IntervalSet::from(AtomicInterval::point(12)));
+        let b = IntervalSet::from(AtomicInterval::closed(3, 7))
+            .union(&IntervalSet::from(AtomicInterval::closed_open(9, 12)));
+
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(union, a.union(&b));
+
+        let mut intersection = a.clone();
+        intersection.intersection_with(&b);
+        assert_eq!(intersection, a.intersection(&b));
+
+        let mut difference = a.clone();
+        difference.difference_with(&b);
+        assert_eq!(difference, a.difference(&b));
+    }
+
+    #[test]
+    fn test_find() {
+        let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::open(5, 8)))
+            .union(&IntervalSet::from(AtomicInterval::point(10)));
+        assert_eq!(set.find(&1), Some(0));
+        assert_eq!(set.find(&3), Some(0));
+        assert_eq!(set.find(&6), Some(1));
+        assert_eq!(set.find(&10), Some(2));
+        assert_eq!(set.find(&0), None);
+        assert_eq!(set.find(&4), None);
+        assert_eq!(set.find(&5), None);
+        assert_eq!(set.find(&8), None);
+        assert_eq!(set.find(&11), None);
+        assert_eq!(IntervalSet::new().find(&1), None);
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = IntervalSet::from(AtomicInterval::closed(1.0, 3.0))
+            .union(&IntervalSet::from(AtomicInterval::open(5.0, 8.0)));
+        assert!(set.contains(&2.5));
+        assert!(set.contains(&7.9));
+        assert!(!set.contains(&4.0));
+        assert!(!set.contains(&8.0));
+    }
+
+    #[test]
+    fn test_intersection_of_unsorted_set() {
+        let unsorted = IntervalSet {
+            intervals: vec![AtomicInterval::closed(6, 9), AtomicInterval::closed(1, 4), AtomicInterval::closed(3, 5)],
+        };
+        let other = IntervalSet::from(AtomicInterval::closed(2, 7));
+        let expected = vec![AtomicInterval::closed(2, 5), AtomicInterval::closed(6, 7)];
+        assert_eq!(unsorted.intersection(&other).intervals, expected);
+        assert_eq!(other.intersection(&unsorted).intervals, expected);
+    }
+
+    #[test]
+    fn test_difference_of_unsorted_set() {
+        let unsorted = IntervalSet {
+            intervals: vec![AtomicInterval::closed(6, 9), AtomicInterval::closed(1, 4), AtomicInterval::closed(3, 5)],
+        };
+        let other = IntervalSet::from(AtomicInterval::open(2, 7));
+        assert_eq!(unsorted.difference(&other).intervals, vec![AtomicInterval::closed(1, 2), AtomicInterval::closed(7, 9)]);
+
+        let mut in_place = unsorted.clone();
+        in_place.difference_with(&other);
+        assert_eq!(in_place, unsorted.difference(&other));
+    }
+
+    #[test]
+    fn test_holes_within() {
+        let set = IntervalSet::from(AtomicInterval::closed(3, 5));
+        assert_eq!(set.holes_within(&AtomicInterval::closed(1, 10)).intervals, vec![
+            AtomicInterval::closed_open(1, 3),
+            AtomicInterval::open_closed(5, 10),
+        ]);
+        assert_eq!(IntervalSet::new().holes_within(&AtomicInterval::open(1, 10)).intervals, vec![AtomicInterval::open(1, 10)]);
+    }
+
+    #[test]
+    fn test_holes_within_set_poking_outside_window() {
+        let set = IntervalSet::from(AtomicInterval::closed(-5, 2))
+            .union(&IntervalSet::from(AtomicInterval::open(4, 6)))
+            .union(&IntervalSet::from(AtomicInterval::closed(8, 20)));
+        assert_eq!(set.holes_within(&AtomicInterval::closed(0, 10)).intervals, vec![
+            AtomicInterval::open_closed(2, 4),
+            AtomicInterval::closed_open(6, 8),
+        ]);
+        assert!(set.holes_within(&AtomicInterval::closed(9, 12)).is_empty());
+    }
+
+    #[test]
+    fn test_difference_of_point_set() {
+        let set = IntervalSet::from(AtomicInterval::closed(1, 5));
+        let points = IntervalSet::from(AtomicInterval::point(1))
+            .union(&IntervalSet::from(AtomicInterval::point(3)))
+            .union(&IntervalSet::from(AtomicInterval::point(5)));
+        assert_eq!(set.difference(&points).intervals, vec![AtomicInterval::open(1, 3), AtomicInterval::open(3, 5)]);
+    }
+
+    #[test]
+    fn test_coalesce() {
+        let set = IntervalSet::from(AtomicInterval::closed(1, 5))
+            .union(&IntervalSet::from(AtomicInterval::closed(6, 9)));
+        assert_eq!(set.coalesce(1).intervals, vec![AtomicInterval::closed(1, 9)]);
+        assert_eq!(set.coalesce(0), set);
+    }
+
+    #[test]
+    fn test_coalesce_bridges_excluded_bounds() {
+        let set = IntervalSet::from(AtomicInterval::open(1, 5))
+            .union(&IntervalSet::from(AtomicInterval::open(5, 9)))
+            .union(&IntervalSet::from(AtomicInterval::closed_open(11, 12)));
+        assert_eq!(set.coalesce(0).intervals, vec![AtomicInterval::open(1, 9), AtomicInterval::closed_open(11, 12)]);
+        assert_eq!(set.coalesce(2).intervals, vec![AtomicInterval::open(1, 12)]);
+    }
+
+    #[test]
+    fn test_wrapping_splits_wrapping_interval() {
+        let set = IntervalSet::wrapping(vec![AtomicInterval::closed_open(80000, 90000)], 86400);
+        assert_eq!(set.intervals, vec![
+            AtomicInterval::closed_open(0, 3600),
+            AtomicInterval::closed_open(80000, 86400),
+        ]);
+        assert!(set.contains(&86399));
+        assert!(set.contains(&0));
+        assert!(set.contains(&3599));
+        assert!(!set.contains(&3600));
+        assert!(!set.contains(&79999));
+    }
+
+    #[test]
+    fn test_wrapping_shifts_intervals_into_domain() {
+        let set = IntervalSet::wrapping(vec![
+            AtomicInterval::closed(25, 27),
+            AtomicInterval::open(-3, -1),
+            AtomicInterval::closed(8, 10),
+        ], 10);
+        assert_eq!(set.intervals, vec![
+            AtomicInterval::point(0),
+            AtomicInterval::closed_open(5, 10),
+        ]);
+    }
+
+    #[test]
+    fn test_wrapping_long_interval_covers_domain() {
+        let set = IntervalSet::wrapping(vec![AtomicInterval::closed(3, 20)], 10);
+        assert_eq!(set.intervals, vec![AtomicInterval::closed_open(0, 10)]);
+        let set = IntervalSet::wrapping(vec![AtomicInterval::open(3, 13)], 10);
+        assert_eq!(set.intervals, vec![AtomicInterval::closed_open(0, 3), AtomicInterval::open(3, 10)]);
+    }
+
+    #[test]
+    fn test_try_union() {
+        let a = IntervalSet::from(AtomicInterval::closed(1.0, 5.0));
+        let b = IntervalSet::from(AtomicInterval::closed(3.0, 7.0));
+        assert_eq!(a.try_union(&b), Ok(a.union(&b)));
+        assert_eq!(a.try_union(&IntervalSet::new()), Ok(a.clone()));
+    }
+
+    #[test]
+    fn test_try_union_with_nan_bound() {
+        let a = IntervalSet::from(AtomicInterval::closed(1.0, 5.0));
+        let b = IntervalSet::from(AtomicInterval::closed(f64::NAN, 7.0));
+        assert_eq!(a.try_union(&b), Err(IntervalError::IncomparableBound));
+        assert_eq!(b.try_union(&a), Err(IntervalError::IncomparableBound));
+        let c = IntervalSet::from(AtomicInterval::point(f64::NAN));
+        assert_eq!(c.try_union(&IntervalSet::new()), Err(IntervalError::IncomparableBound));
+    }
+
+    #[test]
+    fn test_union_with_nan_bound_does_not_panic() {
+        let nan = AtomicInterval::closed(f64::NAN, 2.0);
+        let a = IntervalSet { intervals: vec![nan.clone(), AtomicInterval::closed(3.0, 4.0)] };
+        let b = IntervalSet::from(AtomicInterval::closed(0.0, 1.0));
+
+        let union = a.union(&b);
+        assert_eq!(union.intervals.len(), 3);
+        assert_eq!(union.intervals[0], AtomicInterval::closed(0.0, 1.0));
+        assert_eq!(union.intervals[1], AtomicInterval::closed(3.0, 4.0));
+        // The NaN-bounded interval is placed last.
+        assert!(union.intervals[2].left().value().is_nan());
+        assert_eq!(b.union(&a).intervals.len(), 3);
+    }
+
+    #[test]
+    fn test_segments() {
+        let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::closed(6, 8)));
+        let segments: Vec<_> = set.segments().collect();
+        assert_eq!(segments, vec![
+            Segment::Covered(AtomicInterval::closed(1, 3)),
+            Segment::Gap(AtomicInterval::open(3, 6)),
+            Segment::Covered(AtomicInterval::closed(6, 8)),
+        ]);
+    }
+
+    #[test]
+    fn test_segments_of_unnormalized_and_empty_sets() {
+        assert_eq!(IntervalSet::<i32>::new().segments().count(), 0);
+
+        let set = IntervalSet {
+            intervals: vec![AtomicInterval::open(5, 8), AtomicInterval::closed_open(1, 4), AtomicInterval::closed_open(2, 5)],
+        };
+        assert_eq!(set.segments().collect::<Vec<_>>(), vec![
+            Segment::Covered(AtomicInterval::closed_open(1, 5)),
+            Segment::Gap(AtomicInterval::point(5)),
+            Segment::Covered(AtomicInterval::open(5, 8)),
+        ]);
+    }
+
+    #[test]
+    fn test_from_points_with_consecutive_run() {
+        let set = IntervalSet::from_points(vec![5, 3, 4, 4, 7]);
+        assert_eq!(set.intervals, vec![AtomicInterval::closed(3, 5), AtomicInterval::point(7)]);
+        assert!(set.is_normalized());
+    }
+
+    #[test]
+    fn test_from_points_with_isolated_points() {
+        let set = IntervalSet::from_points([10u8, 1, 255]);
+        assert_eq!(set.intervals, vec![
+            AtomicInterval::point(1),
+            AtomicInterval::point(10),
+            AtomicInterval::point(255),
+        ]);
+        assert!(IntervalSet::<i32>::from_points([]).is_empty());
+    }
+
+    #[test]
+    fn test_atoms_round_trip() {
+        let set = IntervalSet::from(AtomicInterval::closed(1, 3))
+            .union(&IntervalSet::from(AtomicInterval::open(5, 8)));
+        assert_eq!(set.to_atoms(), set.intervals);
+        assert_eq!(IntervalSet::from(set.clone().into_atoms()), set);
+    }
+
+    #[test]
+    fn test_atoms_are_normalized() {
+        let set = IntervalSet {
+            intervals: vec![AtomicInterval::closed(6, 9), AtomicInterval::closed(1, 3), AtomicInterval::closed(2, 4)],
+        };
+        let expected = vec![AtomicInterval::closed(1, 4), AtomicInterval::closed(6, 9)];
+        assert_eq!(set.to_atoms(), expected);
+        assert_eq!(set.into_atoms(), expected);
+    }
+
+    #[test]
+    fn test_merge_adjacent_on_sorted_set() {
+        let mut set = IntervalSet {
+            intervals: vec![
+                AtomicInterval::closed(1, 4),
+                AtomicInterval::closed(2, 3),
+                AtomicInterval::closed_open(4, 6),
+                AtomicInterval::closed(6, 7),
+                AtomicInterval::open(7, 9),
+                AtomicInterval::closed(10, 12),
+            ],
+        };
+        set.merge_adjacent();
+        assert_eq!(set.intervals, vec![AtomicInterval::closed_open(1, 9), AtomicInterval::closed(10, 12)]);
+        assert!(set.is_normalized());
+    }
+
+    #[test]
+    fn test_merge_adjacent_on_empty_and_unsorted_sets() {
+        let mut empty = IntervalSet::<i32>::new();
+        empty.merge_adjacent();
+        assert!(empty.is_empty());
+
+        // Only neighbors are merged when the precondition is not met.
+        let mut set = IntervalSet {
+            intervals: vec![AtomicInterval::closed(5, 6), AtomicInterval::closed(1, 2), AtomicInterval::closed(6, 7)],
+        };
+        set.merge_adjacent();
+        assert_eq!(set.intervals.len(), 3);
+    }
+
+    #[test]
+    fn test_builder_matches_from_vec() {
+        // A simple linear congruential generator keeps the test deterministic.
+        let mut state: u64 = 42;
+        let mut next = move || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as i64
+        };
+
+        let mut atoms: Vec<AtomicInterval<i64>> = (0..1000)
+            .map(|_| {
+                let left = next() % 10_000;
+                AtomicInterval::closed_open(left, left + 1 + next() % 20)
+            })
+            .collect();
+        // Shuffle with a Fisher-Yates pass.
+        for i in (1..atoms.len()).rev() {
+            atoms.swap(i, next() as usize % (i + 1));
+        }
+
+        let mut builder = IntervalSetBuilder::with_capacity(atoms.len());
+        for atom in atoms.iter().cloned() {
+            builder.push(atom);
         }
+        let built = builder.build();
 
-        IntervalSet { intervals: result }
+        assert_eq!(built, IntervalSet::from(atoms));
+        assert!(built.is_normalized());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_builder_chaining_and_extend() {
+        let mut builder = IntervalSetBuilder::default();
+        builder.push(AtomicInterval::closed(8, 9)).push(AtomicInterval::closed(1, 2));
+        builder.extend(vec![AtomicInterval::closed(2, 3)]);
+        assert_eq!(builder.build().intervals, vec![AtomicInterval::closed(1, 3), AtomicInterval::closed(8, 9)]);
+        assert!(IntervalSetBuilder::<i32>::new().build().is_empty());
+    }
 
     #[test]
-    fn test_interval_from_atomic_interval() {
-        let atomic_interval = AtomicInterval::closed(1, 5);
-        let interval_set: IntervalSet<i32> = IntervalSet::from(atomic_interval.clone());
-        assert_eq!(interval_set.intervals.len(), 1);
-        assert_eq!(interval_set.intervals[0], atomic_interval);
+    fn test_map_values_with_negation() {
+        let set = IntervalSet::from(AtomicInterval::closed(1, 3));
+        assert_eq!(set.map_values(|x| -x).intervals, vec![AtomicInterval::closed(-3, -1)]);
+
+        let set = IntervalSet::from(vec![AtomicInterval::closed_open(1, 3), AtomicInterval::open(5, 8)]);
+        assert_eq!(set.map_values(|x| -x).intervals, vec![
+            AtomicInterval::open(-8, -5),
+            AtomicInterval::open_closed(-3, -1),
+        ]);
     }
 
     #[test]
-    fn test_union_between_two_overlapping_intervals() {
-        let interval1 = AtomicInterval::closed(1, 3);
-        let interval2 = AtomicInterval::closed(4, 7);
-        let interval3 = AtomicInterval::closed(2, 4);
-        let interval4 = AtomicInterval::closed(7, 8);
-        let union = IntervalSet::from(interval1).union(&IntervalSet::from(interval2));
-        let union = union.union(&IntervalSet::from(interval3));
-        let union = union.union(&IntervalSet::from(interval4));
-        assert_eq!(union.intervals.len(), 1);
-        assert_eq!(union.intervals[0], AtomicInterval::closed(1, 8));
+    fn test_map_values_renormalizes() {
+        let set = IntervalSet::from(vec![AtomicInterval::closed(-3, -1), AtomicInterval::closed(2, 4)]);
+        assert_eq!(set.map_values(|x: &i32| x.abs()).intervals, vec![AtomicInterval::closed(1, 4)]);
+        assert_eq!(set.map_values(|x| *x as f64 * 0.5).intervals, vec![
+            AtomicInterval::closed(-1.5, -0.5),
+            AtomicInterval::closed(1.0, 2.0),
+        ]);
+        let open = IntervalSet::from(AtomicInterval::open(1, 3));
+        assert_eq!(open.map_values(|_| 0).intervals, vec![AtomicInterval::point(0)]);
     }
 
     #[test]
-    fn test_union_between_two_disjoint_intervals() {
-        let interval1 = AtomicInterval::closed(1, 3);
-        let interval2 = AtomicInterval::closed(4, 7);
-        let interval3 = AtomicInterval::closed(5, 8);
-        let union = IntervalSet::from(interval1).union(&IntervalSet::from(interval2));
-        let union = union.union(&IntervalSet::from(interval3));
-        assert_eq!(union.intervals.len(), 2);
-        assert_eq!(union.intervals[0], AtomicInterval::closed(1, 3));
-        assert_eq!(union.intervals[1], AtomicInterval::closed(4, 8));
+    fn test_equals_pointset() {
+        let touching = IntervalSet {
+            intervals: vec![AtomicInterval::closed(1, 3), AtomicInterval::closed(3, 5)],
+        };
+        let whole = IntervalSet::from(AtomicInterval::closed(1, 5));
+        assert!(touching.equals_pointset(&whole));
+        assert!(whole.equals_pointset(&touching));
+
+        let unsorted = IntervalSet {
+            intervals: vec![AtomicInterval::open_closed(3, 5), AtomicInterval::closed(1, 3)],
+        };
+        assert!(unsorted.equals_pointset(&whole));
     }
 
     #[test]
-    fn test_intersection_between_two_overlapping_intervals() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::closed(3, 7);
-        let interval1 = IntervalSet::from(interval1);
-        let interval2 = IntervalSet::from(interval2);
-        let intersection = interval1.intersection(&interval2);
-        assert_eq!(intersection.intervals.len(), 1);
-        assert_eq!(intersection.intervals[0], AtomicInterval::closed(3, 5));
+    fn test_equals_pointset_with_different_points() {
+        let whole = IntervalSet::from(AtomicInterval::closed(1, 5));
+        let holed = IntervalSet {
+            intervals: vec![AtomicInterval::closed_open(1, 3), AtomicInterval::open_closed(3, 5)],
+        };
+        assert!(!holed.equals_pointset(&whole));
+        assert!(!whole.equals_pointset(&IntervalSet::new()));
+        assert!(IntervalSet::<i32>::new().equals_pointset(&IntervalSet::new()));
     }
 
     #[test]
-    fn test_intersection_between_two_disjoint_intervals() {
-        let interval1 = AtomicInterval::closed(1, 3);
-        let interval2 = AtomicInterval::closed(4, 7);
-        let interval1 = IntervalSet::from(interval1);
-        let interval2 = IntervalSet::from(interval2);
-        let intersection = interval1.intersection(&interval2);
-        assert!(intersection.is_empty());
+    fn test_cluster() {
+        let set = IntervalSet::from(vec![
+            AtomicInterval::closed(1, 3),
+            AtomicInterval::closed(4, 6),
+            AtomicInterval::closed(20, 22),
+        ]);
+        let clusters = set.cluster(5);
+        assert_eq!(clusters, vec![
+            IntervalSet::from(vec![AtomicInterval::closed(1, 3), AtomicInterval::closed(4, 6)]),
+            IntervalSet::from(AtomicInterval::closed(20, 22)),
+        ]);
+        assert!(clusters.iter().all(|cluster| cluster.is_normalized()));
     }
 
     #[test]
-    fn test_difference_between_two_overlapping_intervals() {
-        let interval1 = AtomicInterval::closed(1, 5);
-        let interval2 = AtomicInterval::closed(3, 7);
-        let interval1 = IntervalSet::from(interval1);
-        let interval2 = IntervalSet::from(interval2);
-        let difference = interval1.difference(&interval2);
-        assert_eq!(difference.intervals.len(), 1);
-        assert_eq!(difference.intervals[0], AtomicInterval::closed_open(1, 3));
+    fn test_cluster_edge_cases() {
+        assert!(IntervalSet::<i32>::new().cluster(5).is_empty());
+
+        let set = IntervalSet::from(vec![AtomicInterval::closed(1, 3), AtomicInterval::closed(8, 9)]);
+        assert_eq!(set.cluster(5).len(), 1);
+        assert_eq!(set.cluster(4).len(), 2);
+        assert_eq!(set.cluster(0).len(), 2);
     }
 
     #[test]
-    fn test_difference_between_two_disjoint_intervals() {
-        let interval1 = AtomicInterval::closed(1, 3);
-        let interval2 = AtomicInterval::closed(4, 7);
-        let interval1 = IntervalSet::from(interval1);
-        let interval2 = IntervalSet::from(interval2);
-        let difference = interval1.difference(&interval2);
-        assert_eq!(difference.intervals.len(), 1);
-        assert_eq!(difference.intervals[0], AtomicInterval::closed(1, 3));
+    #[should_panic]
+    fn test_cluster_with_negative_gap() {
+        IntervalSet::from(AtomicInterval::closed(1, 3)).cluster(-1);
+    }
+
+    #[test]
+    fn test_intersect_atom() {
+        let set = IntervalSet::from(vec![AtomicInterval::closed(1, 5), AtomicInterval::closed(8, 12)]);
+        let window = AtomicInterval::closed(3, 9);
+        let clipped = set.intersect_atom(&window);
+        assert_eq!(clipped.intervals, vec![AtomicInterval::closed(3, 5), AtomicInterval::closed(8, 9)]);
+        assert_eq!(clipped, set.intersection(&IntervalSet::from(window)));
+    }
+
+    #[test]
+    fn test_intersect_atom_without_overlap() {
+        let set = IntervalSet::from(vec![AtomicInterval::closed_open(1, 5), AtomicInterval::closed(8, 12)]);
+        assert!(set.intersect_atom(&AtomicInterval::closed(5, 7)).is_empty());
+        assert!(set.intersect_atom(&AtomicInterval::closed(20, 30)).is_empty());
+        assert!(IntervalSet::new().intersect_atom(&AtomicInterval::closed(1, 2)).is_empty());
+        assert_eq!(
+            set.intersect_atom(&AtomicInterval::closed(12, 30)).intervals,
+            vec![AtomicInterval::point(12)],
+        );
+    }
+
+    #[test]
+    fn test_depth_at() {
+        let set = IntervalSet {
+            intervals: vec![AtomicInterval::closed(1, 5), AtomicInterval::closed(3, 8), AtomicInterval::open(2, 4)],
+        };
+        assert_eq!(set.depth_at(&3), 3);
+        assert_eq!(set.depth_at(&2), 1);
+        assert_eq!(set.depth_at(&6), 1);
+        assert_eq!(set.depth_at(&9), 0);
+        assert_eq!(set.clone().normalize().depth_at(&3), 1);
+    }
+
+    #[test]
+    fn test_set_operations_normalize_broken_sets() {
+        let broken = IntervalSet {
+            intervals: vec![AtomicInterval::closed(6, 9), AtomicInterval::closed(1, 4), AtomicInterval::closed(3, 6)],
+        };
+        assert!(!broken.is_normalized());
+
+        // Each operation checks its result in debug builds, so reaching the asserts means the checks held.
+        let other = IntervalSet::from(AtomicInterval::closed(2, 3));
+        assert!(broken.union(&other).is_normalized());
+        assert!(broken.intersection(&other).is_normalized());
+        assert!(broken.difference(&other).is_normalized());
+        assert!(other.difference(&broken).is_normalized());
+    }
+
+    #[test]
+    fn test_to_ranges_inclusive() {
+        let set = IntervalSet::from(vec![AtomicInterval::closed(5, 8), AtomicInterval::closed(1, 3)]);
+        assert_eq!(set.to_ranges_inclusive(), Ok(vec![1..=3, 5..=8]));
+        assert_eq!(IntervalSet::<i32>::new().to_ranges_inclusive(), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_to_ranges_inclusive_with_open_atom() {
+        let set = IntervalSet::from(vec![AtomicInterval::closed(1, 3), AtomicInterval::closed_open(5, 8)]);
+        assert_eq!(set.to_ranges_inclusive(), Err(IntervalError::ExcludedBound));
+    }
+
+    #[test]
+    fn test_to_ranges_half_open() {
+        let set = IntervalSet::from(vec![
+            AtomicInterval::closed(1, 3),
+            AtomicInterval::open_closed(4, 6),
+            AtomicInterval::closed_open(10, 12),
+        ]);
+        assert_eq!(set.to_ranges_half_open(), Some(vec![1..4, 5..7, 10..12]));
+        let set = IntervalSet::from(AtomicInterval::closed(250u8, 255));
+        assert_eq!(set.to_ranges_half_open(), None);
+    }
+
+    #[test]
+    fn test_to_ranges_half_open_skips_intervals_without_values() {
+        let set = IntervalSet::from(AtomicInterval::open(4, 5));
+        assert_eq!(set.to_ranges_half_open(), Some(vec![]));
+        let set = IntervalSet::from(vec![AtomicInterval::closed(1, 2), AtomicInterval::open(4, 5), AtomicInterval::open(7, 10)]);
+        assert_eq!(set.to_ranges_half_open(), Some(vec![1..3, 8..10]));
+        let set = IntervalSet::from(vec![AtomicInterval::open(1u8, 2), AtomicInterval::open_closed(250, 255)]);
+        assert_eq!(set.to_ranges_half_open(), None);
+    }
+
+    #[test]
+    fn test_map_atoms_merges_expanded_atoms() {
+        let set = IntervalSet::from(vec![AtomicInterval::closed(1, 3), AtomicInterval::open(6, 9)]);
+        assert_eq!(set.map_atoms(|atom| atom.expand(1)).intervals, vec![
+            AtomicInterval::closed(0, 4),
+            AtomicInterval::open(5, 10),
+        ]);
+        assert_eq!(set.map_atoms(|atom| atom.expand(2)).intervals, vec![AtomicInterval::closed_open(-1, 11)]);
+    }
+
+    #[test]
+    fn test_map_atoms_renormalizes() {
+        let set = IntervalSet::from(vec![AtomicInterval::closed(1, 3), AtomicInterval::closed(10, 12)]);
+        let reflected = set.map_atoms(|atom| atom.reflect(0));
+        assert_eq!(reflected.intervals, vec![AtomicInterval::closed(-12, -10), AtomicInterval::closed(-3, -1)]);
+        assert!(IntervalSet::<i32>::new().map_atoms(|atom| atom.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_reinterpret_bounds_as_half_open() {
+        let closed = IntervalSet {
+            intervals: vec![AtomicInterval::closed(1, 3), AtomicInterval::closed(3, 5), AtomicInterval::closed(8, 9)],
+        };
+        assert_eq!(closed.reinterpret_bounds(true, false).intervals, vec![
+            AtomicInterval::closed_open(1, 5),
+            AtomicInterval::closed_open(8, 9),
+        ]);
+    }
+
+    #[test]
+    fn test_reinterpret_bounds_changes_adjacency() {
+        let half_open = IntervalSet {
+            intervals: vec![AtomicInterval::closed_open(1, 3), AtomicInterval::closed_open(3, 5)],
+        };
+        // Touching half-open intervals merge, but open ones leave their shared endpoint out.
+        assert_eq!(half_open.clone().normalize().intervals, vec![AtomicInterval::closed_open(1, 5)]);
+        assert_eq!(half_open.reinterpret_bounds(false, false).intervals, vec![
+            AtomicInterval::open(1, 3),
+            AtomicInterval::open(3, 5),
+        ]);
+        assert_eq!(half_open.reinterpret_bounds(true, true).intervals, vec![AtomicInterval::closed(1, 5)]);
+    }
+
+    #[test]
+    fn test_reinterpret_bounds_drops_points() {
+        let set = IntervalSet::from(vec![AtomicInterval::point(2), AtomicInterval::closed(4, 6)]);
+        assert_eq!(set.reinterpret_bounds(false, true).intervals, vec![AtomicInterval::open_closed(4, 6)]);
+        assert_eq!(set.reinterpret_bounds(true, true), set);
+    }
+
+    #[test]
+    fn test_pairs() {
+        let set = IntervalSet::from(vec![
+            AtomicInterval::closed(1, 2),
+            AtomicInterval::closed(5, 9),
+            AtomicInterval::closed(12, 15),
+        ]);
+        assert_eq!(set.pairs().count(), 2);
+        assert_eq!(set.pairs().last(), Some((&set.intervals[1], &set.intervals[2])));
+    }
+
+    #[test]
+    fn test_pairs_of_small_sets() {
+        assert_eq!(IntervalSet::<i32>::new().pairs().count(), 0);
+        assert_eq!(IntervalSet::from(AtomicInterval::closed(1, 2)).pairs().count(), 0);
     }
 }
\ No newline at end of file
@@ -0,0 +1,182 @@
+//! Randomized invariant tests for the interval set algebra.
+//!
+//! Membership is checked on a grid of half-integer points, so that the open
+//! space between two integer endpoints is probed as well as the endpoints themselves.
+
+use proptest::prelude::*;
+use timekeep_rs::{AtomicInterval, Bound, IntervalSet};
+
+/// Range of the generated endpoints.
+const MIN: i32 = -20;
+const MAX: i32 = 20;
+
+/// Checks whether `x / 2` lies in the atomic interval.
+fn atom_contains_half(interval: &AtomicInterval<i32>, x: i32) -> bool {
+    let left = match interval.left() {
+        Bound::Included(l) => x >= 2 * l,
+        Bound::Excluded(l) => x > 2 * l,
+    };
+    let right = match interval.right() {
+        Bound::Included(r) => x <= 2 * r,
+        Bound::Excluded(r) => x < 2 * r,
+    };
+    left && right
+}
+
+/// Checks whether `x / 2` lies in any interval of the set.
+fn set_contains_half(set: &IntervalSet<i32>, x: i32) -> bool {
+    set.intervals.iter().any(|interval| atom_contains_half(interval, x))
+}
+
+/// All probe points, covering a margin around the endpoint range.
+fn probes() -> impl Iterator<Item = i32> {
+    (2 * MIN - 2)..=(2 * MAX + 2)
+}
+
+/// Checks that every point of `a` is also a point of `b`.
+fn is_subset(a: &IntervalSet<i32>, b: &IntervalSet<i32>) -> bool {
+    probes().all(|x| !set_contains_half(a, x) || set_contains_half(b, x))
+}
+
+/// Checks that the set is sorted and that no two consecutive atoms overlap or touch.
+fn is_normalized(set: &IntervalSet<i32>) -> bool {
+    set.intervals.windows(2).all(|pair| {
+        pair[0].left().value() <= pair[1].left().value()
+            && !pair[0].is_overlapping(&pair[1])
+            && !pair[0].is_adjacent(&pair[1])
+    })
+}
+
+fn atomic_interval() -> impl Strategy<Value = AtomicInterval<i32>> {
+    (MIN..MAX, 1..10, 0..5usize).prop_map(|(left, width, style)| {
+        let right = left + width;
+        match style {
+            0 => AtomicInterval::closed(left, right),
+            1 => AtomicInterval::open(left, right),
+            2 => AtomicInterval::closed_open(left, right),
+            3 => AtomicInterval::open_closed(left, right),
+            _ => AtomicInterval::point(left),
+        }
+    })
+}
+
+fn interval_set() -> impl Strategy<Value = IntervalSet<i32>> {
+    prop::collection::vec(atomic_interval(), 0..6).prop_map(|atoms| {
+        atoms
+            .into_iter()
+            .fold(IntervalSet::new(), |set, atom| set.union(&IntervalSet::from(atom)))
+    })
+}
+
+proptest! {
+    #[test]
+    fn atomic_union_is_superset_of_both(a in atomic_interval(), b in atomic_interval()) {
+        for union in AtomicInterval::union(&a, &b) {
+            prop_assert!(union.is_superset(&a));
+            prop_assert!(union.is_superset(&b));
+        }
+    }
+
+    #[test]
+    fn atomic_intersection_is_subset_of_both(a in atomic_interval(), b in atomic_interval()) {
+        for intersection in a.intersection(&b) {
+            prop_assert!(intersection.is_subset(&a));
+            prop_assert!(intersection.is_subset(&b));
+        }
+    }
+
+    #[test]
+    fn atomic_difference_is_disjoint_from_subtrahend(a in atomic_interval(), b in atomic_interval()) {
+        for difference in a.difference(&b) {
+            prop_assert!(difference.is_disjoint(&b));
+            prop_assert!(difference.is_subset(&a));
+        }
+    }
+
+    #[test]
+    fn union_is_superset_of_both(a in interval_set(), b in interval_set()) {
+        let union = a.union(&b);
+        prop_assert!(is_subset(&a, &union));
+        prop_assert!(is_subset(&b, &union));
+        for x in probes() {
+            prop_assert_eq!(set_contains_half(&union, x), set_contains_half(&a, x) || set_contains_half(&b, x));
+        }
+    }
+
+    #[test]
+    fn union_is_normalized(a in interval_set(), b in interval_set()) {
+        prop_assert!(is_normalized(&a.union(&b)));
+    }
+
+    #[test]
+    fn intersection_is_subset_of_both(a in interval_set(), b in interval_set()) {
+        let intersection = a.intersection(&b);
+        prop_assert!(is_subset(&intersection, &a));
+        prop_assert!(is_subset(&intersection, &b));
+        for x in probes() {
+            prop_assert_eq!(set_contains_half(&intersection, x), set_contains_half(&a, x) && set_contains_half(&b, x));
+        }
+    }
+
+    #[test]
+    fn difference_does_not_intersect_subtrahend(a in interval_set(), b in interval_set()) {
+        let difference = a.difference(&b);
+        prop_assert!(difference.intersection(&b).is_empty());
+        for x in probes() {
+            prop_assert_eq!(set_contains_half(&difference, x), set_contains_half(&a, x) && !set_contains_half(&b, x));
+        }
+    }
+
+    #[test]
+    fn insert_matches_union(a in interval_set(), atom in atomic_interval()) {
+        let mut inserted = a.clone();
+        inserted.insert(atom.clone());
+        prop_assert_eq!(inserted, a.union(&IntervalSet::from(atom)));
+    }
+
+    #[test]
+    fn remove_matches_difference(a in interval_set(), atom in atomic_interval()) {
+        let mut removed = a.clone();
+        removed.remove(&atom);
+        prop_assert_eq!(removed, a.difference(&IntervalSet::from(atom)));
+    }
+
+    #[test]
+    fn flatten_is_idempotent(atoms in prop::collection::vec(atomic_interval(), 0..6)) {
+        let flattened = IntervalSet { intervals: atoms.clone() }.flatten();
+        prop_assert!(is_normalized(&flattened));
+        prop_assert_eq!(flattened.clone().flatten(), flattened.clone());
+        for x in probes() {
+            prop_assert_eq!(set_contains_half(&flattened, x), atoms.iter().any(|atom| atom_contains_half(atom, x)));
+        }
+    }
+
+    #[test]
+    fn in_place_operations_match_by_value_results(a in interval_set(), b in interval_set()) {
+        let mut union = a.clone();
+        union.union_with(&b);
+        prop_assert_eq!(union, a.union(&b));
+        let mut intersection = a.clone();
+        intersection.intersection_with(&b);
+        prop_assert_eq!(intersection, a.intersection(&b));
+        let mut difference = a.clone();
+        difference.difference_with(&b);
+        prop_assert_eq!(difference, a.difference(&b));
+    }
+
+    #[test]
+    fn find_matches_linear_search(a in interval_set(), x in 2 * MIN - 2..2 * MAX + 2) {
+        let found = a.find(&x).map(|index| atom_contains_half(&a.intervals[index], 2 * x));
+        prop_assert_eq!(found.is_some(), set_contains_half(&a, 2 * x));
+        prop_assert_ne!(found, Some(false));
+    }
+
+    #[test]
+    fn operations_normalize_their_inputs(a in prop::collection::vec(atomic_interval(), 0..6), b in prop::collection::vec(atomic_interval(), 0..6)) {
+        let (a, b) = (IntervalSet { intervals: a }, IntervalSet { intervals: b });
+        let (normalized_a, normalized_b) = (a.clone().normalize(), b.clone().normalize());
+        prop_assert_eq!(a.union(&b), normalized_a.union(&normalized_b));
+        prop_assert_eq!(a.intersection(&b), normalized_a.intersection(&normalized_b));
+        prop_assert_eq!(a.difference(&b), normalized_a.difference(&normalized_b));
+    }
+}